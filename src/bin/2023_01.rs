@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-advent_of_code::solution!(1);
+advent_of_code::solution!(2023, 1);
 
 fn parse(input: &str) -> Vec<Vec<u32>> {
     
@@ -38,15 +38,13 @@ pub fn part_two(input: &str) -> Option<u32> {
 mod tests {
     use super::*;
 
-    // #[test]
-    // fn test_part_one() {
-    //     let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-    //     assert_eq!(result, Some(142));
-    // }
+    #[test]
+    fn test_part_one() {
+        advent_of_code::template::examples::assert_part(PUZZLE, 1, part_one);
+    }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(281));
+        advent_of_code::template::examples::assert_part(PUZZLE, 2, part_two);
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 fn generate_round(round: &str) -> HashMap<String, u32> {
     round.split(",")
@@ -66,13 +66,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2286));
     }
 }
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use advent_of_code::{Grid, Pos};
+
+advent_of_code::solution!(2023, 3);
+
+fn parse(input: &str) -> Grid<char> {
+    input.parse().unwrap()
+}
+
+fn get_number(grid: &Grid<char>, used: &mut HashSet<Pos>, (i, j): Pos) -> u32 {
+    let row: &[char] = grid.row(i);
+    let mut start: usize = j;
+    let mut end: usize = j;
+    while start > 0 && row[start - 1].is_digit(10) { start -= 1; }
+    while end < row.len() && row[end].is_digit(10) { end += 1; }
+
+    if used.contains(&(i, start)) { return 0; }
+    used.insert((i, start));
+
+    row[start..end].iter()
+        .fold(0, |acc, d| acc * 10 + d.to_digit(10).unwrap())
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_digit(10) && c != '.'
+}
+
+fn is_valid(grid: &Grid<char>, pos: Pos) -> bool {
+    grid[pos].is_digit(10) && grid.neighbours8(pos).any(|n| is_symbol(grid[n]))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid: Grid<char> = parse(input);
+    let mut used: HashSet<Pos> = HashSet::new();
+    let mut ans: Option<u32> = None;
+    for pos in grid.positions() {
+        if is_valid(&grid, pos) {
+            ans = Some(ans.unwrap_or(0) + get_number(&grid, &mut used, pos));
+        }
+    }
+    ans
+}
+
+fn get_gear_numbers(grid: &Grid<char>, used: &mut HashSet<Pos>, pos: Pos) -> Vec<u32> {
+    grid.neighbours8(pos)
+        .filter(|&n| grid[n].is_digit(10))
+        .map(|n| get_number(grid, used, n))
+        .collect()
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let grid: Grid<char> = parse(input);
+    let mut used: HashSet<Pos> = HashSet::new();
+    let mut ans: Option<u64> = None;
+    for pos in grid.find_all(&'*') {
+        let gears: Vec<u32> = get_gear_numbers(&grid, &mut used, pos).into_iter().filter(|n| *n > 0).collect();
+        if gears.len() != 2 { continue; }
+        ans = Some(ans.unwrap_or(0) + gears[0]  as u64 * gears[1] as u64);
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(4361));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(467835));
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 fn parse_numbers(numbers: &str) -> Vec<u32> {
     numbers.trim()
@@ -49,13 +49,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(30));
     }
 }
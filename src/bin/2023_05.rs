@@ -0,0 +1,95 @@
+use advent_of_code::{IntervalSet, RangeMap};
+
+advent_of_code::solution!(2023, 5);
+
+#[derive(Debug)]
+struct Almanac {
+    source: String,
+    destination: String,
+    map: RangeMap<u64>,
+}
+
+fn get_almanac(block: &str) -> Almanac {
+    let info: (&str, &str) = block.split_once("\n").unwrap().0
+        .split_once(" ").unwrap().0
+        .split_once("-to-").unwrap();
+    let map: RangeMap<u64> = block.lines()
+        .skip(1)
+        .map(|line| {
+            let numbers: Vec<u64> = line.split(" ").map(|n| n.parse::<u64>().unwrap()).collect();
+            (numbers[1]..numbers[1] + numbers[2], numbers[0])
+        })
+        .collect();
+    Almanac {
+        source: info.0.to_owned(),
+        destination: info.1.to_owned(),
+        map,
+    }
+}
+
+fn parse(input: &str) -> Vec<Almanac> {
+    input.split("\n\n")
+        .skip(1)
+        .map(|block| get_almanac(block))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let mut stage: String = String::from("seed");
+    let mut seeds: Vec<u64> = input.split_once("\n").unwrap().0
+        .split_once(": ").unwrap().1
+        .split_whitespace()
+        .map(|n| n.parse::<u64>().unwrap())
+        .collect();
+    let maps: Vec<Almanac> = parse(input);
+    while stage != "location" {
+        for almanac in &maps {
+            if almanac.source == stage {
+                stage = almanac.destination.to_owned();
+                seeds = seeds.into_iter().map(|seed| almanac.map.get(seed)).collect();
+                break;
+            }
+        }
+    }
+    seeds.into_iter().min()
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let mut stage: String = String::from("seed");
+    let seed_ranges: Vec<u64> = input.split_once("\n").unwrap().0
+        .split_once(": ").unwrap().1
+        .split(" ")
+        .map(|n| n.parse::<u64>().unwrap())
+        .collect();
+    let mut seeds: IntervalSet<u64> = seed_ranges.chunks(2)
+        .map(|range| range[0]..range[0] + range[1])
+        .collect();
+    let maps: Vec<Almanac> = parse(input);
+    while stage != "location" {
+        for almanac in &maps {
+            if almanac.source == stage {
+                stage = almanac.destination.to_owned();
+                seeds = almanac.map.apply(&seeds);
+                break;
+            }
+        }
+    }
+    seeds.min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE).replace("\r", ""));
+        assert_eq!(result, Some(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE).replace("\r", ""));
+        assert_eq!(result, Some(46));
+    }
+}
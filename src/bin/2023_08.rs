@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use advent_of_code::math::{first_coincidence, lcm};
+
+advent_of_code::solution!(2023, 8);
+
+fn parse(input: &str) -> HashMap<&str, (&str, &str)> {
+    input.lines()
+        .skip(2)
+        .fold(HashMap::new(), |mut acc, line| {
+            let key: &str = line.get(0..3).unwrap();
+            let left: &str = line.get(7..10).unwrap();
+            let right: &str = line.get(12..15).unwrap();
+            acc.insert(key, (left, right));
+            acc
+        })
+}
+
+/// The steps at which a ghost is on a `Z` node.
+#[derive(Debug)]
+struct Ghost {
+    /// hits before the ghost enters its loop, they happen only once.
+    once: Vec<u64>,
+    /// hits inside of its loop, as `(offset, period)`.
+    cycles: Vec<(u64, u64)>,
+}
+
+impl Ghost {
+    fn is_on_z(&self, step: u64) -> bool {
+        self.once.contains(&step) || self.cycles.iter().any(|&(offset, period)| step >= offset && (step - offset).is_multiple_of(period))
+    }
+}
+
+/// Walks a ghost until its state repeats.
+fn walk_ghost<'a>(map: &HashMap<&'a str, (&'a str, &'a str)>, instructions: &[char], start: &'a str) -> Ghost {
+    let mut seen: HashMap<(&str, usize), u64> = HashMap::new();
+    let mut hits: Vec<u64> = Vec::new();
+    let mut node: &str = start;
+    let mut step: u64 = 0;
+    loop {
+        let i: usize = step as usize % instructions.len();
+        if let Some(&loop_start) = seen.get(&(node, i)) {
+            let (cycles, once): (Vec<u64>, Vec<u64>) = hits.into_iter().partition(|&hit| hit >= loop_start);
+            return Ghost { once, cycles: cycles.into_iter().map(|hit| (hit, step - loop_start)).collect() };
+        }
+        seen.insert((node, i), step);
+        if node.ends_with("Z") { hits.push(step); }
+        match instructions[i] {
+            'L' => { node = map.get(node).unwrap().0; },
+            'R' => { node = map.get(node).unwrap().1; },
+            _ => panic!("Invalid instruction"),
+        }
+        step += 1;
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let map: HashMap<&str, (&str, &str)> = parse(input);
+
+    let mut count: u32 = 0;
+    let mut node: &str = "AAA";
+    let mut instruction = input.lines().take(1).next().unwrap().chars().cycle();
+    while node != "ZZZ" {
+        match instruction.next().unwrap() {
+            'L' => { node = map.get(node).unwrap().0; },
+            'R' => { node = map.get(node).unwrap().1; },
+            _ => panic!("Invalid instruction"),
+        }
+        count += 1;
+    }
+    Some(count)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let map: HashMap<&str, (&str, &str)> = parse(input);
+    let instructions: Vec<char> = input.lines().next().unwrap().chars().collect();
+    let ghosts: Vec<Ghost> = map.keys()
+        .filter(|key| key.ends_with("A"))
+        .map(|start| walk_ghost(&map, &instructions, start))
+        .collect();
+
+    // a hit before a ghost enters its loop can still be the answer, so it is checked against every ghost.
+    let early: Option<u64> = ghosts.iter()
+        .flat_map(|ghost| ghost.once.iter().copied())
+        .filter(|&step| ghosts.iter().all(|ghost| ghost.is_on_z(step)))
+        .min();
+
+    // merges the cycles one ghost at a time, every merged cycle being when the ghosts so far coincide.
+    let mut merged: Vec<(u64, u64)> = vec![(0, 1)];
+    for ghost in &ghosts {
+        merged = merged.iter()
+            .flat_map(|&a| ghost.cycles.iter().filter_map(move |&b| Some((first_coincidence([a, b])?, lcm(a.1, b.1)?))))
+            .filter(|&(offset, _)| early.is_none_or(|early| offset < early))
+            .collect();
+        merged.sort_unstable();
+        merged.dedup();
+    }
+    merged.into_iter()
+        .map(|(offset, _)| offset)
+        .chain(early)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::template::examples::assert_part(PUZZLE, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::template::examples::assert_part(PUZZLE, 2, part_two);
+    }
+}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(2023, 9);
 
 fn parse(input: &str) -> Vec<Vec<i32>> {
     input.lines()
@@ -54,13 +54,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 }
@@ -1,11 +1,6 @@
-advent_of_code::solution!(10);
+use advent_of_code::{Dir4, Point2, Polygon};
 
-enum Dir {
-    Left,
-    Right,
-    Up,
-    Down,
-}
+advent_of_code::solution!(2023, 10);
 
 fn get_start(map: &Vec<Vec<char>>) -> Option<(usize, usize)> {
     for i in 0..map.len() {
@@ -18,9 +13,9 @@ fn get_start(map: &Vec<Vec<char>>) -> Option<(usize, usize)> {
     None
 }
 
-fn valid_path(curr_pipe: char, next_pipe: char, dir: Dir) -> bool {
+fn valid_path(curr_pipe: char, next_pipe: char, dir: Dir4) -> bool {
     match dir {
-        Dir::Up => {
+        Dir4::Up => {
             (match curr_pipe {
                 'S' => true,
                 '|' => true,
@@ -34,7 +29,7 @@ fn valid_path(curr_pipe: char, next_pipe: char, dir: Dir) -> bool {
                 _ => false
             })
         }
-        Dir::Down => {
+        Dir4::Down => {
             (match curr_pipe {
                 'S' => true,
                 '|' => true,
@@ -48,7 +43,7 @@ fn valid_path(curr_pipe: char, next_pipe: char, dir: Dir) -> bool {
                 _ => false
             })
         }
-        Dir::Left => {
+        Dir4::Left => {
             (match curr_pipe {
                 'S' => true,
                 '-' => true,
@@ -62,7 +57,7 @@ fn valid_path(curr_pipe: char, next_pipe: char, dir: Dir) -> bool {
                 _ => false
             })
         }
-        Dir::Right => {
+        Dir4::Right => {
             (match curr_pipe {
                 'S' => true,
                 '-' => true,
@@ -88,19 +83,19 @@ fn bfs(map: Vec<Vec<char>>) -> Vec<Vec<char>> {
     while !curr.is_empty() {
         let mut next_nodes: Vec<(usize, usize)> = Vec::new();
         for (i, j) in curr {
-            if i > 0 && !visited[i - 1][j] && valid_path(map[i][j], map[i - 1][j], Dir::Up) {
+            if i > 0 && !visited[i - 1][j] && valid_path(map[i][j], map[i - 1][j], Dir4::Up) {
                 next_nodes.push((i - 1, j));
                 visited[i - 1][j] = true;
             }
-            if j > 0 && !visited[i][j - 1] && valid_path(map[i][j], map[i][j - 1], Dir::Left) {
+            if j > 0 && !visited[i][j - 1] && valid_path(map[i][j], map[i][j - 1], Dir4::Left) {
                 next_nodes.push((i, j - 1));
                 visited[i][j - 1] = true;
             }
-            if i < map.len() - 1 && !visited[i + 1][j] && valid_path(map[i][j], map[i + 1][j], Dir::Down) {
+            if i < map.len() - 1 && !visited[i + 1][j] && valid_path(map[i][j], map[i + 1][j], Dir4::Down) {
                 next_nodes.push((i + 1, j));
                 visited[i + 1][j] = true;
             }
-            if j < map[0].len() - 1 && !visited[i][j + 1] && valid_path(map[i][j], map[i][j + 1], Dir::Right) {
+            if j < map[0].len() - 1 && !visited[i][j + 1] && valid_path(map[i][j], map[i][j + 1], Dir4::Right) {
                 next_nodes.push((i, j + 1));
                 visited[i][j + 1] = true;
             }
@@ -120,44 +115,32 @@ fn bfs(map: Vec<Vec<char>>) -> Vec<Vec<char>> {
         .collect()
 }
 
-fn is_interior(i: usize, j: usize, map: &Vec<Vec<char>>) -> bool {
-    if map[i][j] != ' ' { return false; }
+fn connections(pipe: char) -> [Dir4; 2] {
+    match pipe {
+        '|' => [Dir4::Up, Dir4::Down],
+        '-' => [Dir4::Left, Dir4::Right],
+        'L' => [Dir4::Up, Dir4::Right],
+        'J' => [Dir4::Up, Dir4::Left],
+        '7' => [Dir4::Down, Dir4::Left],
+        'F' => [Dir4::Down, Dir4::Right],
+        _ => panic!("Invalid pipe"),
+    }
+}
 
-    let mut intersections: u32 = 0;
-    let mut last_open: Option<char> = None;
-    for k in i + 1..map.len() {
-        match map[k][j] {
-            '-' => { intersections += 1; },
-            'L' => {
-                match last_open {
-                    Some('7') => { intersections += 1; last_open = None; },
-                    Some('F') => { last_open = None; },
-                    _ => { panic!("Invalid sequence") },
-                }
-            }
-            '7' => {
-                match last_open {
-                    None => { last_open = Some('7'); },
-                    _ => { panic!("Invalid sequence") },
-                }
-            }
-            'J' => {
-                match last_open {
-                    Some('F') => { intersections += 1; last_open = None; },
-                    Some('7') => { last_open = None; },
-                    _ => { panic!("Invalid sequence") },
-                }
-            }
-            'F' => {
-                match last_open {
-                    None => { last_open = Some('F'); },
-                    _ => { panic!("Invalid sequence") },
-                }
-            }
-            _ => {}
-        }
+fn walk_loop(map: &Vec<Vec<char>>, start: (usize, usize)) -> Vec<Point2> {
+    let mut dir: Dir4 = connections(map[start.0][start.1])[0];
+    let start: Point2 = Point2::from(start);
+    let mut pos: Point2 = start;
+    let mut tiles: Vec<Point2> = Vec::new();
+    loop {
+        tiles.push(pos);
+        pos += dir.delta();
+        if pos == start { break; }
+        let (i, j) = pos.to_pos().unwrap();
+        let [a, b] = connections(map[i][j]);
+        dir = if a == dir.reverse() { b } else { a };
     }
-    intersections % 2 != 0
+    tiles
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -168,7 +151,6 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    // Jordan curve theorem
     let mut map = bfs(input.lines().map(|line| line.chars().collect()).collect());
 
     // Assign the right char to S
@@ -209,19 +191,9 @@ pub fn part_two(input: &str) -> Option<u32> {
         _ => { panic!("Invalid starting point") }
     }
 
-    let mut interior_points: u32 = 0;
-    for i in 0..map.len() {
-        for j in 0..map[0].len() {
-            if is_interior(i, j, &map) {
-                //print!("I");
-                interior_points += 1;
-            } else {
-                //print!("{}", map[i][j]);
-            }
-        }
-        //println!();
-    }
-    Some(interior_points)
+    // Pick's theorem on the loop's tiles
+    let tiles: Vec<Point2> = walk_loop(&map, start);
+    Some(Polygon::new(tiles).interior_points() as u32)
 }
 
 #[cfg(test)]
@@ -230,13 +202,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(80));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(10));
     }
 }
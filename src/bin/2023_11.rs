@@ -1,4 +1,4 @@
-advent_of_code::solution!(11);
+advent_of_code::solution!(2023, 11);
 
 fn extend_space(galaxies: &mut Vec<(usize, usize)>, map: Vec<Vec<char>>, extra_space: usize) {
     // Extend rows
@@ -71,13 +71,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(374));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(82000210));
     }
 }
@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2023, 12, parse);
 
 #[derive(Debug)]
-struct Spring {
+pub struct Spring {
     data: Vec<char>,
     groups: Vec<usize>,
 }
@@ -121,20 +121,20 @@ impl Spring {
     }
 }
 
-fn parse(input: &str) -> Vec<Spring> {
+pub fn parse(input: &str) -> Vec<Spring> {
     input.lines()
         .map(|line| Spring::new(line))
         .collect()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    parse(input)
-        .into_iter()
+pub fn part_one(springs: &[Spring]) -> Option<u64> {
+    springs
+        .iter()
         .fold(Some(0), |acc, spring| Some(acc.unwrap() + spring.ways(0, 0,&mut HashMap::new())))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    parse(input).iter_mut() 
+pub fn part_two(springs: &[Spring]) -> Option<u64> {
+    springs.iter()
         .fold(Some(0), |acc, spring| {
             Some(acc.unwrap() + spring.extend(5).ways(0, 0, &mut HashMap::new()))//back_combinations.max(forward_combinations))
         })
@@ -146,13 +146,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", PUZZLE)));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)));
         assert_eq!(result, Some(525152));
     }
 }
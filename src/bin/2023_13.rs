@@ -1,38 +1,34 @@
-advent_of_code::solution!(13);
+use advent_of_code::Grid;
+
+advent_of_code::solution!(2023, 13);
 
 struct Pattern {
-    grid: Vec<Vec<char>>,
+    grid: Grid<char>,
 }
 
 impl Pattern {
     fn new(input: &str) -> Self {
         Self {
-            grid: input.lines()
-                    .map(|line| line.chars().collect())
-                    .collect()
+            grid: input.parse().unwrap(),
         }
     }
 
     fn transpose(&self) -> Self {
-        let len = self.grid.first().unwrap().len();
-        let transposed_pattern: Vec<Vec<char>> = (0..len).into_iter()
-            .map(|i| self.grid.iter().map(|row| row[i]).collect())
-            .collect();
         Self {
-            grid: transposed_pattern,
+            grid: self.grid.transpose(),
         }
     }
 
     fn clean_smudge(&mut self, i: usize, j: usize) {
-        match self.grid[i][j] {
-            '.' => self.grid[i][j] = '#',
-            '#' => self.grid[i][j] = '.',
+        match self.grid[(i, j)] {
+            '.' => self.grid[(i, j)] = '#',
+            '#' => self.grid[(i, j)] = '.',
             _ => panic!("Invalid character"),
         }
     }
 
     fn mirror_position(&self, ignore: Option<usize>) -> Option<usize> {
-        position_horizontal_mirror(self.grid.iter().map(|row| row.iter().collect::<String>()).collect(), ignore)
+        position_horizontal_mirror(self.grid.rows().map(|row| row.iter().collect::<String>()).collect(), ignore)
     }
 }
 
@@ -63,9 +59,9 @@ pub fn part_two(input: &str) -> Option<usize> {
     for (k, pattern) in patterns.iter_mut().enumerate() {
         let original_mirror: (Option<usize>, Option<usize>) = (pattern.mirror_position(None), pattern.transpose().mirror_position(None));
         let mut found: bool = false;
-        for i in 0..pattern.grid.len() {
+        for i in 0..pattern.grid.height() {
             if found { break; }
-            for j in 0..pattern.grid[0].len() {
+            for j in 0..pattern.grid.width() {
                 pattern.clean_smudge(i, j);
 
                 let new_mirror: (Option<usize>, Option<usize>) = (pattern.mirror_position(original_mirror.0), pattern.transpose().mirror_position(original_mirror.1));
@@ -105,13 +101,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY).replace("\r", ""));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE).replace("\r", ""));
         assert_eq!(result, Some(405));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY).replace("\r", ""));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE).replace("\r", ""));
         assert_eq!(result, Some(400));
     }
 }
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-advent_of_code::solution!(14);
+advent_of_code::solution!(2023, 14);
 
 fn tilt_north(grid: &mut Vec<Vec<char>>) -> Option<u32> {
     let mut changes: Option<u32> = None;
@@ -103,13 +103,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(136));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(64));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(15);
+advent_of_code::solution!(2023, 15);
 
 #[derive(Debug, Clone)]
 struct Lens {
@@ -90,13 +90,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(1320));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(145));
     }
 }
@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(2023, 17);
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 enum Direction {
@@ -180,7 +180,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(94));
     }
 }
@@ -0,0 +1,50 @@
+// Shoelace formula & Pick's theorem
+
+use advent_of_code::{Dir4, Polygon};
+
+advent_of_code::solution!(2023, 18);
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let steps = input.lines()
+        .map(|line| {
+            let parts: Vec<&str> = line.split(" ").collect();
+            let dir: Dir4 = parts[0].parse().unwrap();
+            let length: i64 = parts[1].parse().unwrap();
+            (dir, length)
+        });
+    Some(Polygon::from_path(steps).lattice_points())
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let steps = input.lines()
+        .map(|line| {
+            let (length, dir): (&str, &str) = line.split(" ")
+                .collect::<Vec<&str>>()[2]
+                .strip_prefix("(#")
+                .unwrap()
+                .strip_suffix(")")
+                .unwrap()
+                .split_at(5);
+            let dir: Dir4 = dir.parse().unwrap();
+            let length: i64 = i64::from_str_radix(length, 16).unwrap();
+            (dir, length)
+        });
+    Some(Polygon::from_path(steps).lattice_points())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(62));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(952408144115));
+    }
+}
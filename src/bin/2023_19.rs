@@ -1,7 +1,9 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
-advent_of_code::solution!(19);
+use advent_of_code::IntervalSet;
+
+advent_of_code::solution!(2023, 19);
 
 #[derive(Debug)]
 struct Rule {
@@ -54,68 +56,32 @@ impl Rule {
         }
     }
 
+    fn matching_values(&self) -> IntervalSet<u64> {
+        match self.order {
+            None => IntervalSet::from(1..4001),
+            Some(Ordering::Less) => IntervalSet::from(1..self.value.unwrap()),
+            Some(Ordering::Greater) => IntervalSet::from(self.value.unwrap() + 1..4001),
+            Some(Ordering::Equal) => panic!("Invalid comparison"),
+        }
+    }
+
     fn next_range(&self, pice_range: &mut PiceRange) -> PiceRange {
         let mut move_on_pice = pice_range.clone();
         move_on_pice.workflow = self.destination.clone();
-        match self.kind {
+        let index: usize = match self.kind {
             None => {
-                pice_range.x.0 = 4000;
-                pice_range.x.1 = 0;
-            },
-            Some('x') => {
-                match self.order {
-                    Some(Ordering::Less) => {
-                        move_on_pice.x.1 = self.value.unwrap() - 1;
-                        pice_range.x.0 = self.value.unwrap();
-                    },
-                    Some(Ordering::Greater) => {
-                        pice_range.x.1 = self.value.unwrap();
-                        move_on_pice.x.0 = self.value.unwrap() + 1;
-                    },
-                    _ => panic!("Invalid comparison"),
-                }
-            },
-            Some('m') => {
-                match self.order {
-                    Some(Ordering::Less) => {
-                        move_on_pice.m.1 = self.value.unwrap() - 1;
-                        pice_range.m.0 = self.value.unwrap();
-                    },
-                    Some(Ordering::Greater) => {
-                        pice_range.m.1 = self.value.unwrap();
-                        move_on_pice.m.0 = self.value.unwrap() + 1;
-                    },
-                    _ => panic!("Invalid comparison"),
-                }
-            },
-            Some('a') => {
-                match self.order {
-                    Some(Ordering::Less) => {
-                        move_on_pice.a.1 = self.value.unwrap() - 1;
-                        pice_range.a.0 = self.value.unwrap();
-                    },
-                    Some(Ordering::Greater) => {
-                        pice_range.a.1 = self.value.unwrap();
-                        move_on_pice.a.0 = self.value.unwrap() + 1;
-                    },
-                    _ => panic!("Invalid comparison"),
-                }
-            },
-            Some('s') => {
-                match self.order {
-                    Some(Ordering::Less) => {
-                        move_on_pice.s.1 = self.value.unwrap() - 1;
-                        pice_range.s.0 = self.value.unwrap();
-                    },
-                    Some(Ordering::Greater) => {
-                        pice_range.s.1 = self.value.unwrap();
-                        move_on_pice.s.0 = self.value.unwrap() + 1;
-                    },
-                    _ => panic!("Invalid comparison"),
-                }
+                pice_range.ratings = Default::default();
+                return move_on_pice;
             },
+            Some('x') => 0,
+            Some('m') => 1,
+            Some('a') => 2,
+            Some('s') => 3,
             _ => panic!("Invalid kind"),
-        }
+        };
+        let matching: IntervalSet<u64> = self.matching_values();
+        move_on_pice.ratings[index] = pice_range.ratings[index].intersection(&matching);
+        pice_range.ratings[index] = pice_range.ratings[index].difference(&matching);
 
         move_on_pice
     }
@@ -123,10 +89,7 @@ impl Rule {
 
 #[derive(Debug, Clone)]
 struct PiceRange {
-    x: (u64, u64),
-    m: (u64, u64),
-    a: (u64, u64),
-    s: (u64, u64),
+    ratings: [IntervalSet<u64>; 4],
     workflow: String,
 }
 
@@ -142,14 +105,11 @@ impl PiceRange {
     }
 
     fn valid(&self) -> bool {
-        if self.x.0 > self.x.1 || self.m.0 > self.m.1 || self.a.0 > self.a.1 || self.s.0 > self.s.1 {
-            return false;
-        } 
-        true
+        self.ratings.iter().all(|rating| !rating.is_empty())
     }
 
     fn combinations(&self) -> u64 {
-        (self.x.1 - self.x.0 + 1) * (self.m.1 - self.m.0 + 1) * (self.a.1 - self.a.0 + 1) * (self.s.1 - self.s.0 + 1)
+        self.ratings.iter().map(|rating| rating.len()).product()
     }
 }
 
@@ -199,7 +159,7 @@ pub fn part_one(input: &str) -> Option<u64> {
 
 pub fn part_two(input: &str) -> Option<u64> {
     let workflows: HashMap<String, Vec<Rule>> = parse(input.split_once("\n\n").unwrap().0);
-    let mut pice_ranges: Vec<PiceRange> = vec![PiceRange { x: (1, 4000), m: (1, 4000), a: (1, 4000), s: (1, 4000), workflow: String::from("in")}];
+    let mut pice_ranges: Vec<PiceRange> = vec![PiceRange { ratings: std::array::from_fn(|_| IntervalSet::from(1..4001)), workflow: String::from("in")}];
     let mut accepted_pices: Vec<PiceRange> = Vec::new();
     
     while let Some(pice) = pice_ranges.pop().as_mut() {
@@ -222,13 +182,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY).replace("\r", ""));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE).replace("\r", ""));
         assert_eq!(result, Some(19114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY).replace("\r", ""));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE).replace("\r", ""));
         assert_eq!(result, Some(167409079868000));
     }
 }
@@ -1,6 +1,8 @@
 use std::collections::{HashMap, VecDeque};
 
-advent_of_code::solution!(20);
+use advent_of_code::math::first_coincidence;
+
+advent_of_code::solution!(2023, 20);
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -56,9 +58,12 @@ impl Module {
         }
     }
 
-    fn cycle(&self) -> Option<u64> {
+    fn cycle(&self) -> Option<(u64, u64)> {
         match self {
-            Self::Conjunct(module) => module.cycle,
+            Self::Conjunct(module) => match module.highs[..] {
+                [first, second] => Some((first, second - first)),
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -133,8 +138,7 @@ struct Conjunct {
     name: String,
     memory: HashMap<String, Pulse>,
     destinations: Vec<String>,
-    pulses: Result<u64, u64>,
-    cycle: Option<u64>,
+    highs: Vec<u64>,
 }
 
 impl Conjunct {
@@ -144,8 +148,7 @@ impl Conjunct {
             name: name.strip_prefix("&").unwrap().to_string(),
             memory: HashMap::new(),
             destinations: destinations.split(",").map(|s| s.trim().to_string()).collect(),
-            pulses: Err(0),
-            cycle: None,
+            highs: Vec::new(),
         }
     }
 
@@ -158,23 +161,10 @@ impl Conjunct {
     }
 
     fn send(&mut self, pulse: Pulse, iteration: u64) -> VecDeque<(String, Pulse, String)> {
-        match pulse {
-            Pulse::Low => {
-                self.pulses = match self.pulses {
-                    Ok(i) => Err(i),
-                    Err(i) => Err(i),
-                };
-            },
-            Pulse::High => {
-                self.pulses = match self.pulses {
-                    Ok(_) => Ok(iteration),
-                    Err(last_iteration) => {
-                        self.cycle = Some(self.cycle.unwrap_or(0).max(iteration - last_iteration));
-                        Ok(iteration)
-                    },
-                };
-            },
-        };
+        // records the first two iterations with a high pulse, their difference is the period.
+        if pulse == Pulse::High && self.highs.len() < 2 && self.highs.last() != Some(&iteration) {
+            self.highs.push(iteration);
+        }
         self.destinations.iter()
             .map(|s| (s.clone(), pulse, self.name.clone()))
             .collect()
@@ -218,20 +208,6 @@ fn pulse(modules: &mut HashMap<String, Module>, iteration: u64) -> (u64, u64) {
     (low, high)
 }
 
-fn gcd(mut a: u64, mut b: u64) -> u64 {
-    while b != 0 {
-        let t = b;
-        b = a.rem_euclid(b);
-        a = t;
-    }
-    a
-}
-
-fn lcm(a: Option<u64>, b: Option<u64>) -> Option<u64> {
-    if a.is_none() || b.is_none() { return None; }
-    Some((a.unwrap() * b.unwrap()) / gcd(a.unwrap(), b.unwrap()))
-}
-
 pub fn part_one(input: &str) -> Option<u64> {
     let mut modules = parse(input);
     init_conjuncts(&mut modules);
@@ -243,19 +219,30 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(pulses.0 * pulses.1)
 }
 
+/// The button presses after which part two gives up on finding the cycles of the feeders.
+const MAX_PRESSES: u64 = 100_000;
+
 pub fn part_two(input: &str) -> Option<u64> {
     let mut modules = parse(input);
     init_conjuncts(&mut modules);
-    for k in 1..10_000 {
+    // `rx` gets a low pulse once every feeder of the conjunction before it sent a high pulse.
+    let last: String = modules.values()
+        .find(|module| module.destinations().iter().any(|destination| destination == "rx"))?
+        .name();
+    let feeders: Vec<String> = modules.get(&last)?
+        .memory()?
+        .into_keys()
+        .collect();
+    for k in 1..=MAX_PRESSES {
         pulse(&mut modules, k);
+        let cycles: Option<Vec<(u64, u64)>> = feeders.iter()
+            .map(|module_name| modules.get(module_name)?.cycle())
+            .collect();
+        if let Some(cycles) = cycles {
+            return first_coincidence(cycles);
+        }
     }
-    modules.get("lx")
-        .unwrap()
-        .memory()
-        .unwrap()
-        .keys()
-        .map(|module_name| modules.get(module_name).unwrap().cycle())
-        .fold(Some(1), |acc, cycle| lcm(acc, cycle))
+    None
 }
 
 #[cfg(test)]
@@ -264,7 +251,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(11687500));
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-advent_of_code::solution!(21);
+advent_of_code::solution!(2023, 21);
 
 fn bfs(start: (usize, usize), steps: usize, grid: &Vec<Vec<bool>>) -> usize {
     let mut current: Vec<(usize, usize)> = vec![start];
@@ -96,7 +96,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(42));
     }
 }
@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 use std::collections::{HashSet, VecDeque};
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2023, 22);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Brick {
@@ -148,13 +148,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(7));
     }
 }
@@ -1,7 +1,7 @@
 use z3::ast::{Ast, Int};
 use z3::{Config, Context, Solver};
 
-advent_of_code::solution!(24);
+advent_of_code::solution!(2023, 24);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Hail {
@@ -99,7 +99,7 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(47));
     }
 }
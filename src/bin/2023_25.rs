@@ -3,7 +3,7 @@ use petgraph::graph::{NodeIndex, UnGraph, Graph};
 use petgraph::data::FromElements;
 use petgraph::dot::{Dot, Config};
 
-advent_of_code::solution!(25);
+advent_of_code::solution!(2023, 25);
 
 #[derive(Debug)]
 struct Node {
@@ -114,6 +114,126 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days of advent, e.g. to select which days to run.
+///
+/// # Parsing
+/// A set is parsed from a comma separated list of days and inclusive day ranges.
+///
+/// ```
+/// # use advent_of_code::{Day, DaySet};
+/// let days: DaySet = "1-3,12,24-25".parse().unwrap();
+/// assert!(days.contains(Day::new(12).unwrap()));
+/// assert_eq!(days.iter().count(), 6);
+/// assert_eq!(days.to_string(), "1-3,12,24-25")
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DaySet(u32);
+
+impl DaySet {
+    /// Creates a set that contains no day.
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Creates a set that contains every day from the 1st to the 25th.
+    pub fn all() -> Self {
+        all_days().collect()
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.0 & (1 << day.0) != 0
+    }
+
+    pub fn insert(&mut self, day: Day) {
+        self.0 |= 1 << day.0;
+    }
+
+    pub fn remove(&mut self, day: Day) {
+        self.0 &= !(1 << day.0);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the days that are in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// An iterator over the days in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Day> + '_ {
+        all_days().filter(|day| self.contains(*day))
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<I: IntoIterator<Item = Day>>(iter: I) -> Self {
+        let mut set = Self::empty();
+        iter.into_iter().for_each(|day| set.insert(day));
+        set
+    }
+}
+
+impl Display for DaySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let days: Vec<u8> = self.iter().map(Day::into_inner).collect();
+
+        // groups consecutive days into ranges.
+        let mut ranges = days.chunk_by(|a, b| a + 1 == *b).map(|range| match range {
+            [day] => day.to_string(),
+            [first, .., last] => format!("{first}-{last}"),
+            [] => unreachable!("chunks are never empty"),
+        });
+
+        if let Some(first) = ranges.next() {
+            f.write_str(&first)?;
+        }
+        ranges.try_for_each(|range| write!(f, ",{range}"))
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::empty();
+
+        for item in s.split(',').map(str::trim) {
+            let parse_day = |s: &str| s.trim().parse::<Day>().map_err(|_| DaySetFromStrError);
+
+            let (first, last) = match item.split_once('-') {
+                Some((first, last)) => (parse_day(first)?, parse_day(last)?),
+                None => (parse_day(item)?, parse_day(item)?),
+            };
+
+            if first > last {
+                return Err(DaySetFromStrError);
+            }
+
+            all_days()
+                .filter(|day| (first..=last).contains(day))
+                .for_each(|day| set.insert(day));
+        }
+
+        Ok(set)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError;
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a comma separated list of days between 1 and 25 or ranges of them, e.g. \"1-5,12\"")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +254,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +287,44 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn day_set_from_str() {
+        let days: DaySet = "1-5, 12,20-25".parse().unwrap();
+        let expected: Vec<u8> = [1, 2, 3, 4, 5, 12, 20, 21, 22, 23, 24, 25].into();
+
+        assert_eq!(
+            days.iter().map(Day::into_inner).collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(
+            "7".parse::<DaySet>().unwrap().iter().collect::<Vec<_>>(),
+            vec![Day(7)]
+        );
+        assert_eq!("1-25".parse::<DaySet>().unwrap(), DaySet::all());
+
+        assert!("".parse::<DaySet>().is_err());
+        assert!("0-3".parse::<DaySet>().is_err());
+        assert!("5-2".parse::<DaySet>().is_err());
+        assert!("3,26".parse::<DaySet>().is_err());
+        assert!("1-2-3".parse::<DaySet>().is_err());
+    }
+
+    #[test]
+    fn day_set_operations() {
+        let mut days = DaySet::all().difference(&"2-24".parse().unwrap());
+        assert_eq!(days.to_string(), "1,25");
+
+        days.insert(Day(3));
+        days.insert(Day(4));
+        days.remove(Day(25));
+        assert_eq!(days.to_string(), "1,3-4");
+        assert!(days.contains(Day(3)));
+        assert!(!days.contains(Day(2)));
+
+        assert!(DaySet::empty().is_empty());
+        assert_eq!(DaySet::empty().to_string(), "");
+    }
 }
 
 /* -------------------------------------------------------------------------- */
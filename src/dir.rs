@@ -0,0 +1,269 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Point2;
+
+/// One of the four directions along the axes of a grid.
+///
+/// # Parsing
+/// A direction is parsed from `U/R/D/L`, the compass points `N/E/S/W`, the arrows `^>v<`,
+/// or the digits `0` to `3` clockwise from the right as in the hex codes of 2023's day 18.
+///
+/// ```
+/// # use advent_of_code::{Dir4, Point2};
+/// let dir: Dir4 = "R".parse().unwrap();
+/// assert_eq!(dir, "E".parse().unwrap());
+/// assert_eq!(dir, "0".parse().unwrap());
+/// assert_eq!(dir.turn_right(), Dir4::Down);
+/// assert_eq!(dir.reverse().delta(), Point2::new(-1, 0))
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Every direction, clockwise from [`Dir4::Up`].
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Turns a quarter counterclockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Dir4::Up => Dir4::Left,
+            Dir4::Right => Dir4::Up,
+            Dir4::Down => Dir4::Right,
+            Dir4::Left => Dir4::Down,
+        }
+    }
+
+    /// Turns a quarter clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir4::Up => Dir4::Right,
+            Dir4::Right => Dir4::Down,
+            Dir4::Down => Dir4::Left,
+            Dir4::Left => Dir4::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Dir4::Up => Dir4::Down,
+            Dir4::Right => Dir4::Left,
+            Dir4::Down => Dir4::Up,
+            Dir4::Left => Dir4::Right,
+        }
+    }
+
+    /// A step in this direction, `y` grows downwards.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir4::Up => Point2::new(0, -1),
+            Dir4::Right => Point2::new(1, 0),
+            Dir4::Down => Point2::new(0, 1),
+            Dir4::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::Up | Dir4::Down)
+    }
+
+    /// Reads a direction from a single character, see [`Dir4`] for the accepted encodings.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' | '3' => Some(Dir4::Up),
+            'R' | 'E' | '>' | '0' => Some(Dir4::Right),
+            'D' | 'S' | 'v' | '1' => Some(Dir4::Down),
+            'L' | 'W' | '<' | '2' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = DirFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::from_char(c).ok_or(DirFromStrError),
+            _ => Err(DirFromStrError),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the eight directions of a grid, including the diagonals.
+///
+/// # Parsing
+/// A direction is parsed from the compass points `N`, `NE`, `E`, … `NW`, or from any encoding of a [`Dir4`].
+///
+/// ```
+/// # use advent_of_code::{Dir4, Dir8, Point2};
+/// let dir: Dir8 = "NE".parse().unwrap();
+/// assert_eq!(dir.turn_left(), Dir8::from(Dir4::Up));
+/// assert_eq!(dir.reverse().delta(), Point2::new(-1, 1))
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from [`Dir8::N`].
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns an eighth counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    /// Turns an eighth clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// A step in this direction, `y` grows downwards.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir8::N => Point2::new(0, -1),
+            Dir8::NE => Point2::new(1, -1),
+            Dir8::E => Point2::new(1, 0),
+            Dir8::SE => Point2::new(1, 1),
+            Dir8::S => Point2::new(0, 1),
+            Dir8::SW => Point2::new(-1, 1),
+            Dir8::W => Point2::new(-1, 0),
+            Dir8::NW => Point2::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::N,
+            Dir4::Right => Dir8::E,
+            Dir4::Down => Dir8::S,
+            Dir4::Left => Dir8::W,
+        }
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = DirFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "NE" => Ok(Dir8::NE),
+            "SE" => Ok(Dir8::SE),
+            "SW" => Ok(Dir8::SW),
+            "NW" => Ok(Dir8::NW),
+            s => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Dir4`] or [`Dir8`].
+#[derive(Debug)]
+pub struct DirFromStrError;
+
+impl Error for DirFromStrError {}
+
+impl Display for DirFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a direction like \"U\", \"N\", \"^\", \"0\" or \"NE\"")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Dir4, Dir8};
+    use crate::Point2;
+
+    #[test]
+    fn dir4_turns() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.reverse().reverse(), dir);
+            assert_eq!(dir.delta() + dir.reverse().delta(), Point2::ORIGIN);
+            assert_eq!(dir.is_vertical(), dir.delta().x == 0);
+        }
+
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Down.delta(), Point2::new(0, 1));
+    }
+
+    #[test]
+    fn dir8_turns() {
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.reverse().reverse(), dir);
+            assert_eq!(dir.delta() + dir.reverse().delta(), Point2::ORIGIN);
+            assert_eq!(dir.is_diagonal(), dir.delta().x != 0 && dir.delta().y != 0);
+        }
+
+        assert_eq!(Dir8::N.turn_right(), Dir8::NE);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::SW.reverse(), Dir8::NE);
+        for dir in Dir4::ALL {
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+    }
+
+    #[test]
+    fn parses_directions() {
+        let parse = |s: &str| s.parse::<Dir4>().ok();
+
+        assert_eq!(["U", "R", "D", "L"].map(parse), Dir4::ALL.map(Some));
+        assert_eq!(["N", "E", "S", "W"].map(parse), Dir4::ALL.map(Some));
+        assert_eq!(["^", ">", "v", "<"].map(parse), Dir4::ALL.map(Some));
+        assert_eq!(["3", "0", "1", "2"].map(parse), Dir4::ALL.map(Some));
+        assert_eq!(parse(" R "), Some(Dir4::Right));
+        assert_eq!(parse("RR"), None);
+        assert_eq!(parse("4"), None);
+        assert_eq!(parse(""), None);
+
+        assert_eq!("NE".parse::<Dir8>().unwrap(), Dir8::NE);
+        assert_eq!("L".parse::<Dir8>().unwrap(), Dir8::W);
+        assert!("NNE".parse::<Dir8>().is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use crate::math::gcd;
+use crate::{Dir4, Point2};
+
+/// Where a point lies relative to a [`Polygon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple polygon on the integer lattice, e.g. a loop through a grid or a dug out trench.
+///
+/// The vertices are in order along the boundary, the edge from the last vertex back to the first one is implicit.
+/// Areas are computed exactly with the shoelace formula, and the lattice points with Pick's theorem.
+///
+/// ```
+/// # use advent_of_code::{Dir4, Location, Point2, Polygon};
+/// let square = Polygon::from_path([(Dir4::Right, 2), (Dir4::Down, 2), (Dir4::Left, 2), (Dir4::Up, 2)]);
+/// assert_eq!(square.twice_area(), 8);
+/// assert_eq!(square.boundary_points(), 8);
+/// assert_eq!(square.interior_points(), 1);
+/// assert_eq!(square.locate(Point2::new(1, 1)), Location::Inside)
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2>) -> Self {
+        Self { vertices }
+    }
+
+    /// Creates a polygon by walking a path of direction and length steps from the origin.
+    /// The path is expected to end where it started.
+    pub fn from_path(steps: impl IntoIterator<Item = (Dir4, i64)>) -> Self {
+        let mut pos = Point2::ORIGIN;
+        let mut vertices = vec![];
+
+        for (dir, length) in steps {
+            vertices.push(pos);
+            pos += dir.delta() * length;
+        }
+
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point2] {
+        &self.vertices
+    }
+
+    /// An iterator over the edges of the polygon, including the one that closes it.
+    pub fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the enclosed area, which is always an integer for a lattice polygon.
+    pub fn twice_area(&self) -> u64 {
+        let sum: i128 = self
+            .edges()
+            .map(|(a, b)| i128::from(a.x) * i128::from(b.y) - i128::from(b.x) * i128::from(a.y))
+            .sum();
+
+        u64::try_from(sum.abs()).expect("area fits into u64")
+    }
+
+    /// The number of lattice points on the boundary of the polygon.
+    pub fn boundary_points(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside the polygon, by Pick's theorem `A = I + B / 2 - 1`.
+    pub fn interior_points(&self) -> u64 {
+        if self.vertices.len() < 3 {
+            return 0;
+        }
+
+        // NOTE: twice the area of a simple polygon is at least its boundary points minus 2.
+        (self.twice_area() + 2).saturating_sub(self.boundary_points()) / 2
+    }
+
+    /// The number of lattice points inside or on the boundary of the polygon.
+    pub fn lattice_points(&self) -> u64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Locates a point relative to the polygon, with exact integer arithmetic.
+    pub fn locate(&self, point: Point2) -> Location {
+        let mut is_inside = false;
+
+        for (a, b) in self.edges() {
+            if is_on_segment(point, a, b) {
+                return Location::Boundary;
+            }
+
+            // casts a ray to the right and counts the edges it crosses, each edge covers its lower end only.
+            if (a.y > point.y) != (b.y > point.y) {
+                let lhs = i128::from(point.x - a.x) * i128::from(b.y - a.y);
+                let rhs = i128::from(point.y - a.y) * i128::from(b.x - a.x);
+                let is_left_of_edge = if b.y > a.y { lhs < rhs } else { lhs > rhs };
+
+                if is_left_of_edge {
+                    is_inside = !is_inside;
+                }
+            }
+        }
+
+        if is_inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    /// Whether the point lies inside or on the boundary of the polygon.
+    pub fn contains(&self, point: Point2) -> bool {
+        self.locate(point) != Location::Outside
+    }
+}
+
+fn is_on_segment(point: Point2, a: Point2, b: Point2) -> bool {
+    let cross = i128::from(b.x - a.x) * i128::from(point.y - a.y)
+        - i128::from(b.y - a.y) * i128::from(point.x - a.x);
+
+    cross == 0
+        && (a.x.min(b.x)..=a.x.max(b.x)).contains(&point.x)
+        && (a.y.min(b.y)..=a.y.max(b.y)).contains(&point.y)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Location, Polygon};
+    use crate::{Dir4, Point2};
+
+    fn polygon(vertices: &[(i64, i64)]) -> Polygon {
+        Polygon::new(vertices.iter().map(|&(x, y)| Point2::new(x, y)).collect())
+    }
+
+    #[test]
+    fn computes_area() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.twice_area(), 32);
+
+        // the orientation of the vertices does not matter.
+        let reversed = polygon(&[(0, 4), (4, 4), (4, 0), (0, 0)]);
+        assert_eq!(reversed.twice_area(), 32);
+
+        let triangle = polygon(&[(0, 0), (3, 0), (0, 1)]);
+        assert_eq!(triangle.twice_area(), 3);
+
+        assert_eq!(Polygon::default().twice_area(), 0);
+    }
+
+    #[test]
+    fn counts_lattice_points() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.lattice_points(), 25);
+
+        let diagonal = polygon(&[(0, 0), (4, 2), (0, 4)]);
+        assert_eq!(diagonal.boundary_points(), 2 + 2 + 4);
+        assert_eq!(diagonal.interior_points(), 5);
+
+        assert_eq!(polygon(&[(1, 1), (5, 1)]).interior_points(), 0);
+    }
+
+    #[test]
+    fn builds_polygon_from_path() {
+        // the trench of the example of 2023's day 18.
+        let steps = [
+            "R 6", "D 5", "L 2", "D 2", "R 2", "D 2", "L 5", "U 2", "L 1", "U 2", "R 2", "U 3",
+            "L 2", "U 2",
+        ]
+        .map(|step| {
+            let (dir, length) = step.split_once(' ').unwrap();
+            (dir.parse::<Dir4>().unwrap(), length.parse().unwrap())
+        });
+
+        let trench = Polygon::from_path(steps);
+        assert_eq!(trench.vertices().len(), 14);
+        assert_eq!(trench.vertices()[1], Point2::new(6, 0));
+        assert_eq!(trench.boundary_points(), 38);
+        assert_eq!(trench.lattice_points(), 62);
+    }
+
+    #[test]
+    fn locates_points() {
+        // a U shape, its notch is outside.
+        let shape = polygon(&[
+            (0, 0),
+            (2, 0),
+            (2, 2),
+            (4, 2),
+            (4, 0),
+            (6, 0),
+            (6, 4),
+            (0, 4),
+        ]);
+
+        assert_eq!(shape.locate(Point2::new(1, 1)), Location::Inside);
+        assert_eq!(shape.locate(Point2::new(3, 1)), Location::Outside);
+        assert_eq!(shape.locate(Point2::new(3, 3)), Location::Inside);
+        assert_eq!(shape.locate(Point2::new(3, 2)), Location::Boundary);
+        assert_eq!(shape.locate(Point2::new(6, 4)), Location::Boundary);
+        assert_eq!(shape.locate(Point2::new(7, 2)), Location::Outside);
+        assert_eq!(shape.locate(Point2::new(-1, 0)), Location::Outside);
+
+        assert!(shape.contains(Point2::new(0, 2)));
+        assert!(!shape.contains(Point2::new(3, 0)));
+
+        // every interior lattice point is found by both methods.
+        let inside = (0..=6)
+            .flat_map(|x| (0..=4).map(move |y| Point2::new(x, y)))
+            .filter(|&p| shape.locate(p) == Location::Inside)
+            .count();
+        assert_eq!(inside as u64, shape.interior_points());
+    }
+}
@@ -0,0 +1,471 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::Dir4;
+
+/// A position in a [`Grid`] as `(row, col)`, the top left cell is `(0, 0)`.
+pub type Pos = (usize, usize);
+
+/// A rectangular grid of cells, stored row by row in a single vector.
+///
+/// # Parsing
+/// A grid of characters is parsed from lines of equal length, [`Grid::parse_with`] maps every character.
+///
+/// ```
+/// # use advent_of_code::Grid;
+/// let grid: Grid<char> = "#.\n.#\n..".parse().unwrap();
+/// assert_eq!((grid.width(), grid.height()), (2, 3));
+/// assert_eq!(grid[(1, 1)], '#');
+/// assert_eq!(grid.find(&'#'), Some((0, 0)));
+/// assert_eq!(grid.to_string(), "#.\n.#\n..")
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row-major order,
+    /// returns [`None`] if their number is not a multiple of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 {
+            return cells.is_empty().then_some(Self::default());
+        }
+        if !cells.len().is_multiple_of(width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a grid from lines of equal length, mapping every character with `f`.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridFromStrError> {
+        let mut width = None;
+        let mut cells = vec![];
+
+        for (row, line) in input.lines().enumerate() {
+            let start = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let len = cells.len() - start;
+
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(GridFromStrError {
+                        row,
+                        expected: width,
+                        found: len,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(Self::from_vec(width.unwrap_or_default(), cells).unwrap_or_default())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index_of(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let index = self.index_of(pos);
+        Some(&mut self.cells[index])
+    }
+
+    fn index_of(&self, (row, col): Pos) -> usize {
+        row * self.width + col
+    }
+
+    fn pos_of(&self, index: usize) -> Pos {
+        (index / self.width, index % self.width)
+    }
+
+    /// An iterator over every position and its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.pos_of(i), cell))
+    }
+
+    /// An iterator over every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.cells.len()).map(|i| self.pos_of(i))
+    }
+
+    /// The cells of the grid in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    /// An iterator over the neighbours above, right, below and left of `pos` that lie in the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        const OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+        OFFSETS
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// An iterator over the neighbours of `pos` that lie in the grid, including diagonals, clockwise from the top left.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+        ];
+        OFFSETS
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// Moves `pos` by `(rows, cols)`, returns [`None`] if it leaves the grid.
+    pub fn offset(&self, (row, col): Pos, (rows, cols): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(rows)?, col.checked_add_signed(cols)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Moves `pos` one step in `dir`, returns [`None`] if it leaves the grid.
+    pub fn step(&self, pos: Pos, dir: Dir4) -> Option<Pos> {
+        let delta = dir.delta();
+        self.offset(pos, (delta.y as isize, delta.x as isize))
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    /// The cells of a row, panics if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// An iterator over the rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// An iterator over the cells of a column from top to bottom, panics if `col` is out of bounds.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(col < self.width, "column {col} is out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// An iterator over the columns of the grid, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    /// Finds the first position of `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|i| self.pos_of(i))
+    }
+
+    /// An iterator over every position of `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Creates a grid of the same size with every cell mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, |(row, col)| (col, row))
+    }
+
+    /// Rotates the grid by a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, |(row, col)| (self.height - 1 - col, row))
+    }
+
+    /// Rotates the grid by a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, |(row, col)| (col, self.width - 1 - row))
+    }
+
+    /// Creates a grid of the given width, the cell at each position is taken from `source(pos)` of `self`.
+    fn rearrange(&self, width: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.cells.len().checked_div(width).unwrap_or_default();
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        if width == 0 || height == 0 {
+            return Self::default();
+        }
+
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Default for Grid<T> {
+    /// An empty grid without any cell.
+    fn default() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        assert!(self.contains(pos), "position {pos:?} is out of bounds");
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        assert!(self.contains(pos), "position {pos:?} is out of bounds");
+        let index = self.index_of(pos);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|cell| write!(f, "{cell}"))?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Grid<char> {
+    type Err = GridFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`] whose lines differ in length.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GridFromStrError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Error for GridFromStrError {}
+
+impl Display for GridFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting {} cells in row {} like in the first row, found {}",
+            self.expected, self.row, self.found
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, GridFromStrError};
+    use crate::Dir4;
+
+    fn sample() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grid() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.cells(), ['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+
+        let digits = Grid::parse_with("12\n34\n", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits[(1, 0)], 3);
+
+        assert_eq!(
+            "ab\nc\nde".parse::<Grid<char>>(),
+            Err(GridFromStrError {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+
+        let empty: Grid<char> = "".parse().unwrap();
+        assert!(empty.is_empty());
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+
+    #[test]
+    fn creates_grid() {
+        assert_eq!(Grid::from_vec(2, vec![1, 2, 3, 4]).unwrap()[(1, 1)], 4);
+        assert_eq!(Grid::from_vec(2, vec![1, 2, 3]), None);
+        assert_eq!(Grid::from_vec(0, vec![1]), None);
+
+        let mut grid = Grid::filled(3, 2, false);
+        grid[(1, 2)] = true;
+        *grid.get_mut((0, 0)).unwrap() = true;
+        assert_eq!(grid.get_mut((2, 0)), None);
+        assert_eq!(grid.find_all(&true).collect::<Vec<_>>(), [(0, 0), (1, 2)]);
+        assert_eq!(grid.map(|&b| u8::from(b)).to_string(), "100\n001");
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = Grid::filled(3, 3, ());
+
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            [(0, 1), (1, 2), (2, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 0)).collect::<Vec<_>>(),
+            [(1, 0), (1, 1), (2, 1)]
+        );
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (2, 2)), Some((2, 2)));
+        assert_eq!(grid.offset((0, 0), (3, 0)), None);
+        assert_eq!(grid.step((0, 0), Dir4::Right), Some((0, 1)));
+        assert_eq!(grid.step((0, 0), Dir4::Up), None);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let mut grid = sample();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(Iterator::collect)
+                .collect::<Vec<String>>(),
+            ["ad", "be", "cf"]
+        );
+
+        grid.row_mut(0).reverse();
+        assert_eq!(grid.to_string(), "cba\ndef");
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = sample();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+        assert_eq!(Grid::<char>::default().rotate_cw(), Grid::default());
+    }
+
+    #[test]
+    fn finds_values() {
+        let grid: Grid<char> = "..S\n.#.\nS..".parse().unwrap();
+
+        assert_eq!(grid.find(&'S'), Some((0, 2)));
+        assert_eq!(grid.find(&'X'), None);
+        assert_eq!(grid.find_all(&'S').collect::<Vec<_>>(), [(0, 2), (2, 0)]);
+        assert_eq!(grid.iter().filter(|(_, &c)| c == '.').count(), 6);
+        assert_eq!(grid.positions().last(), Some((2, 2)));
+    }
+}
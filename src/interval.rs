@@ -0,0 +1,445 @@
+use std::iter::Sum;
+use std::ops::{Add, Range, Sub};
+
+/// A set of values stored as sorted, disjoint and non-adjacent half-open ranges.
+///
+/// Every constructor normalises its ranges: empty ranges are dropped, overlapping and adjacent ones are merged.
+///
+/// ```
+/// # use advent_of_code::IntervalSet;
+/// let set = IntervalSet::from_ranges([5..8, 1..3, 3..4, 9..9]);
+/// assert_eq!(set.ranges(), [1..4, 5..8]);
+///
+/// let other = IntervalSet::from_ranges([2..6]);
+/// assert_eq!(set.union(&other).ranges(), [1..8]);
+/// assert_eq!(set.intersection(&other).ranges(), [2..4, 5..6]);
+/// assert_eq!(set.difference(&other).ranges(), [1..2, 6..8])
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    pub fn from_ranges(ranges: impl IntoIterator<Item = Range<T>>) -> Self {
+        Self {
+            ranges: normalise(ranges.into_iter().collect()),
+        }
+    }
+
+    /// The normalised ranges of the set, in ascending order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // the ranges are sorted, so the candidate is the last range that starts at or before the value.
+        let i = self.ranges.partition_point(|r| r.start <= value);
+        i > 0 && value < self.ranges[i - 1].end
+    }
+
+    /// The smallest value of the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        self.ranges.push(range);
+        self.ranges = normalise(std::mem::take(&mut self.ranges));
+    }
+
+    /// Returns the values that are in `self` or in `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).cloned())
+    }
+
+    /// Returns the values that are in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // the range that ends first cannot overlap any later range of the other set.
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // NOTE: pieces of disjoint sorted ranges are already normalised.
+        Self { ranges }
+    }
+
+    /// Returns the values that are in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            // skips the ranges of `other` that end before this range.
+            while other.ranges.get(j).is_some_and(|r| r.end <= start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            while let Some(cut) = other.ranges.get(k).filter(|r| r.start < range.end) {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Sum<T>> IntervalSet<T> {
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::from_ranges([range])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::from_ranges(iter)
+    }
+}
+
+/// Sorts the ranges, drops empty ones and merges overlapping or adjacent ones.
+fn normalise<T: Copy + Ord>(mut ranges: Vec<Range<T>>) -> Vec<Range<T>> {
+    ranges.retain(|r| r.start < r.end);
+    ranges.sort_unstable_by_key(|r| r.start);
+
+    let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A piecewise mapping that shifts source ranges onto destination ranges, like the maps of 2023's day 5.
+///
+/// A value is shifted by the first entry whose source contains it, values outside of every source map to themselves.
+///
+/// ```
+/// # use advent_of_code::{IntervalSet, RangeMap};
+/// let map = RangeMap::from_iter([(98..100, 50), (50..98, 52)]);
+/// assert_eq!(map.get(79), 81);
+/// assert_eq!(map.get(10), 10);
+///
+/// let seeds = IntervalSet::from_ranges([79..93, 55..68]);
+/// assert_eq!(map.apply(&seeds).ranges(), [57..70, 81..95])
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeMap<T> {
+    entries: Vec<(Range<T>, T)>,
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> RangeMap<T> {
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Adds an entry that maps `source` onto the range starting at `destination`.
+    /// Values already covered by an earlier entry keep its mapping.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        self.entries.push((source, destination));
+    }
+
+    /// The entries as source range and destination start, in the order they were added.
+    pub fn entries(&self) -> &[(Range<T>, T)] {
+        &self.entries
+    }
+
+    pub fn get(&self, value: T) -> T {
+        self.entries
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(source, destination)| {
+                *destination + (value - source.start)
+            })
+    }
+
+    /// Maps every value of the set, splitting its ranges exactly at the bounds of the sources.
+    pub fn apply(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut remaining = set.clone();
+        let mut mapped = vec![];
+
+        for (source, destination) in &self.entries {
+            let source_set = IntervalSet::from(source.clone());
+
+            mapped.extend(
+                remaining
+                    .intersection(&source_set)
+                    .ranges
+                    .into_iter()
+                    .map(|r| {
+                        (*destination + (r.start - source.start))
+                            ..(*destination + (r.end - source.start))
+                    }),
+            );
+            remaining = remaining.difference(&source_set);
+
+            if remaining.is_empty() {
+                break;
+            }
+        }
+
+        IntervalSet::from_ranges(mapped.into_iter().chain(remaining.ranges))
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> FromIterator<(Range<T>, T)>
+    for RangeMap<T>
+{
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::ops::Range;
+
+    use super::{IntervalSet, RangeMap};
+
+    #[test]
+    fn normalises_ranges() {
+        let set = IntervalSet::from_ranges([7..9, 1..3, 2..5, 5..6, 8..8, 4..2]);
+        assert_eq!(set.ranges(), [1..6, 7..9]);
+        assert_eq!(set.len(), 7);
+        assert_eq!(set.min(), Some(1));
+
+        assert!(IntervalSet::from_ranges([3..3]).is_empty());
+        assert_eq!(IntervalSet::<u32>::default().min(), None);
+    }
+
+    #[test]
+    fn inserts_and_contains() {
+        let mut set = IntervalSet::new();
+        set.insert(10..20);
+        set.insert(0..5);
+        set.insert(5..10);
+        assert_eq!(set.ranges(), [0..20]);
+
+        set.insert(30..40);
+        assert!(set.contains(0) && set.contains(19) && set.contains(35));
+        assert!(!set.contains(20) && !set.contains(29) && !set.contains(40));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = IntervalSet::from_ranges([0..10, 20..30]);
+        let b = IntervalSet::from_ranges([5..25, 28..40]);
+
+        assert_eq!(a.union(&b).ranges(), [0..40]);
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), [10..20, 30..40]);
+        assert!(a.difference(&a).is_empty());
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+
+    #[test]
+    fn maps_ranges() {
+        // the seed-to-soil and soil-to-fertilizer maps of the example of 2023's day 5.
+        let soil = RangeMap::from_iter([(98..100, 50), (50..98, 52)]);
+        let fertilizer = RangeMap::from_iter([(15..52, 0), (52..54, 37), (0..15, 39)]);
+
+        assert_eq!([79, 14, 55, 13].map(|v| soil.get(v)), [81, 14, 57, 13]);
+        assert_eq!(
+            [81, 14, 57, 13].map(|v| fertilizer.get(v)),
+            [81, 53, 57, 52]
+        );
+
+        let seeds = IntervalSet::from_ranges([79..93, 55..68, 95..101]);
+        assert_eq!(
+            soil.apply(&seeds).ranges(),
+            [50..52, 57..70, 81..95, 97..101]
+        );
+    }
+
+    #[test]
+    fn earlier_entries_win() {
+        let mut map = RangeMap::new();
+        map.insert(0..10, 100);
+        map.insert(5..15, 200);
+
+        assert_eq!(map.get(7), 107);
+        assert_eq!(map.get(12), 207);
+        assert_eq!(
+            map.apply(&IntervalSet::from(0..15)).ranges(),
+            [100..110, 205..210]
+        );
+    }
+
+    /* ---------------------------------------------------------------------- */
+    // Properties checked against brute force expansion of random sets of small values.
+
+    const DOMAIN: u32 = 64;
+    const CASES: usize = 500;
+
+    /// A xorshift generator, so that every run checks the same cases.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, bound: u32) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % u64::from(bound)) as u32
+        }
+
+        fn range(&mut self) -> Range<u32> {
+            let (a, b) = (self.next(DOMAIN), self.next(DOMAIN));
+            // also yields empty and reversed ranges.
+            if self.next(4) == 0 {
+                b..a
+            } else {
+                a.min(b)..a.max(b)
+            }
+        }
+
+        fn ranges(&mut self) -> Vec<Range<u32>> {
+            (0..self.next(6)).map(|_| self.range()).collect()
+        }
+    }
+
+    fn expand(ranges: &[Range<u32>]) -> BTreeSet<u32> {
+        ranges.iter().flat_map(Clone::clone).collect()
+    }
+
+    fn assert_normalised(set: &IntervalSet<u32>) {
+        let ranges = set.ranges();
+        assert!(ranges.iter().all(|r| r.start < r.end), "{ranges:?}");
+        assert!(
+            ranges.windows(2).all(|w| w[0].end < w[1].start),
+            "{ranges:?}"
+        );
+    }
+
+    #[test]
+    fn prop_normalise_keeps_values() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..CASES {
+            let ranges = rng.ranges();
+            let set = IntervalSet::from_ranges(ranges.clone());
+
+            assert_normalised(&set);
+            assert_eq!(expand(set.ranges()), expand(&ranges));
+            assert_eq!(set.len() as usize, expand(&ranges).len());
+            for value in 0..DOMAIN {
+                assert_eq!(set.contains(value), expand(&ranges).contains(&value));
+            }
+        }
+    }
+
+    #[test]
+    fn prop_set_operations() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..CASES {
+            let (a, b) = (rng.ranges(), rng.ranges());
+            let (set_a, set_b) = (
+                IntervalSet::from_ranges(a.clone()),
+                IntervalSet::from_ranges(b.clone()),
+            );
+            let (values_a, values_b) = (expand(&a), expand(&b));
+
+            let union = set_a.union(&set_b);
+            let intersection = set_a.intersection(&set_b);
+            let difference = set_a.difference(&set_b);
+
+            for set in [&union, &intersection, &difference] {
+                assert_normalised(set);
+            }
+            assert_eq!(expand(union.ranges()), &values_a | &values_b);
+            assert_eq!(expand(intersection.ranges()), &values_a & &values_b);
+            assert_eq!(expand(difference.ranges()), &values_a - &values_b);
+        }
+    }
+
+    #[test]
+    fn prop_range_map_matches_points() {
+        let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+
+        for _ in 0..CASES {
+            let map: RangeMap<u32> = (0..rng.next(5))
+                .map(|_| (rng.range(), rng.next(DOMAIN)))
+                .collect();
+            let set = IntervalSet::from_ranges(rng.ranges());
+
+            // maps every value on its own, with the first entry whose source contains it.
+            let expected: BTreeSet<u32> = expand(set.ranges())
+                .into_iter()
+                .map(|value| {
+                    map.entries()
+                        .iter()
+                        .find(|(source, _)| source.contains(&value))
+                        .map_or(value, |(source, destination)| {
+                            destination + value - source.start
+                        })
+                })
+                .collect();
+
+            let mapped = map.apply(&set);
+            assert_normalised(&mapped);
+            assert_eq!(expand(mapped.ranges()), expected);
+            assert!(expand(set.ranges())
+                .iter()
+                .all(|&v| mapped.contains(map.get(v))));
+        }
+    }
+}
@@ -1,4 +1,21 @@
+// lets `solution!` refer to this crate by name when solutions are compiled into the registry.
+extern crate self as advent_of_code;
+
 mod day;
+mod dir;
+mod geometry;
+mod grid;
+mod interval;
+pub mod math;
+mod point;
+pub mod solutions;
 pub mod template;
+mod year;
 
 pub use day::*;
+pub use dir::*;
+pub use geometry::*;
+pub use grid::*;
+pub use interval::*;
+pub use point::*;
+pub use year::*;
@@ -1,55 +1,185 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::{
+    commands::{all, bench, download, examples, read, readme, scaffold, solve, verify, watch},
+    runner::RunOptions,
+};
 use args::{parse, AppArguments};
 
 mod args {
-    use std::process;
+    use std::{process, time::Duration};
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{
+            commands::{bench::DEFAULT_THRESHOLD, scaffold::DEFAULT_ANSWER_TYPE},
+            report::OutputFormat,
+            runner::{parse_timeout, DEFAULT_TIMEOUT},
+            InputSource,
+        },
+        DaySet, PuzzleId, Year,
+    };
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
+            answer_type: String,
+            parse: bool,
+            force: bool,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
+            alloc: bool,
             time: bool,
             submit: Option<u8>,
+            format: OutputFormat,
+            timeout: Option<Duration>,
+            input: InputSource,
+        },
+        WatchDay {
+            puzzle: PuzzleId,
+            release: bool,
         },
         All {
+            year: Year,
+            days: DaySet,
+            unsolved_only: bool,
             release: bool,
             time: bool,
+            format: OutputFormat,
+            jobs: usize,
+            timeout: Option<Duration>,
+        },
+        Verify {
+            year: Year,
+            timeout: Option<Duration>,
+        },
+        Readme {
+            year: Year,
+        },
+        Examples {
+            puzzle: PuzzleId,
+            write: bool,
+        },
+        Bench {
+            year: Year,
+            compare: Option<Option<String>>,
+            threshold: f64,
         },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
+        let subcommand = args.subcommand()?;
+
+        // every subcommand works on a single year, `AOC_YEAR` in `.cargo/config.toml` is the default.
+        let year: Year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => {
+                Year::from_env().ok_or("no year selected, pass `--year YYYY` or set `AOC_YEAR`")?
+            }
+        };
+        let puzzle = |args: &mut pico_args::Arguments| -> Result<PuzzleId, pico_args::Error> {
+            Ok(PuzzleId::new(year, args.free_from_str()?))
+        };
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                timeout: args
+                    .opt_value_from_fn("--timeout", parse_timeout)?
+                    .unwrap_or(Some(DEFAULT_TIMEOUT)),
+                unsolved_only: args.contains("--unsolved-only"),
+                days: {
+                    let exclude: Option<DaySet> = args.opt_value_from_str("--exclude")?;
+                    // the day set is an optional free argument, so it is parsed after all options.
+                    let days: DaySet = args.opt_free_from_str()?.unwrap_or_else(DaySet::all);
+                    days.difference(&exclude.unwrap_or_default())
+                },
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                answer_type: args
+                    .opt_value_from_str("--type")?
+                    .unwrap_or_else(|| DEFAULT_ANSWER_TYPE.into()),
+                parse: args.contains("--parse"),
+                force: args.contains("--force"),
+                puzzle: puzzle(&mut args)?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+            Some("solve") => {
+                let submit: Option<u8> = args.opt_value_from_str("--submit")?;
+                let input = match (
+                    args.contains("--example"),
+                    args.opt_value_from_str("--input")?,
+                ) {
+                    (true, Some(_)) => {
+                        return Err("`--example` and `--input` exclude each other".into())
+                    }
+                    (true, None) => InputSource::Example,
+                    (false, Some(input)) => input,
+                    (false, None) => InputSource::Puzzle,
+                };
+                // answers of any other input would be wrong for the puzzle.
+                if submit.is_some() && input != InputSource::Puzzle {
+                    return Err("`--submit` only works with the puzzle input".into());
+                }
+                AppArguments::Solve {
+                    release: args.contains("--release"),
+                    alloc: args.contains("--alloc"),
+                    submit,
+                    time: args.contains("--time"),
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    timeout: args
+                        .opt_value_from_fn("--timeout", parse_timeout)?
+                        .flatten(),
+                    input,
+                    puzzle: puzzle(&mut args)?,
+                }
+            }
+            Some("watch-day") => AppArguments::WatchDay {
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                puzzle: puzzle(&mut args)?,
+            },
+            Some("bench") => {
+                let is_compare = args.contains("--compare");
+                let threshold = args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(DEFAULT_THRESHOLD);
+                // the baseline commit is an optional free argument after `--compare`.
+                let baseline = if is_compare {
+                    Some(args.opt_free_from_str()?)
+                } else {
+                    None
+                };
+                AppArguments::Bench {
+                    year,
+                    compare: baseline,
+                    threshold,
+                }
+            }
+            Some("verify") => AppArguments::Verify {
+                year,
+                timeout: args
+                    .opt_value_from_fn("--timeout", parse_timeout)?
+                    .unwrap_or(Some(DEFAULT_TIMEOUT)),
+            },
+            Some("readme") => AppArguments::Readme { year },
+            Some("examples") => AppArguments::Examples {
+                write: args.contains("--write"),
+                puzzle: puzzle(&mut args)?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -77,16 +207,64 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                days,
+                unsolved_only,
+                release,
+                time,
+                format,
+                jobs,
+                timeout,
+            } => {
+                let options = RunOptions {
+                    format,
+                    is_timed: time,
+                    submit: None,
+                    is_quiet: false,
+                    timeout,
+                };
+                let summary = all::handle(year, days, unsolved_only, release, jobs, options);
+                if summary.failures > 0 {
+                    std::process::exit(1);
+                }
+            }
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold {
+                puzzle,
+                answer_type,
+                parse,
+                force,
+            } => scaffold::handle(puzzle, &answer_type, parse, force),
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
+                alloc,
                 time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                format,
+                timeout,
+                input,
+            } => {
+                let options = RunOptions {
+                    format,
+                    is_timed: time,
+                    submit,
+                    is_quiet: false,
+                    timeout,
+                };
+                solve::handle(puzzle, release, alloc, options, &input);
+            }
+            AppArguments::WatchDay { puzzle, release } => watch::handle(puzzle, release),
+            AppArguments::Verify { year, timeout } => verify::handle(year, timeout),
+            AppArguments::Readme { year } => readme::handle(year),
+            AppArguments::Examples { puzzle, write } => examples::handle(puzzle, write),
+            AppArguments::Bench {
+                year,
+                compare,
+                threshold,
+            } => bench::handle(year, compare, threshold),
         },
     };
 }
@@ -0,0 +1,295 @@
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The primitive integer types the helpers of this module are generic over.
+pub trait Integer:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The absolute value, unsigned integers are returned as is.
+    fn abs(self) -> Self;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),* => |$n:ident| $abs:expr) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    let $n = self;
+                    $abs
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize => |n| n.abs());
+impl_integer!(u8, u16, u32, u64, u128, usize => |n| n);
+
+/* -------------------------------------------------------------------------- */
+
+/// The greatest common divisor, which is never negative and `0` only when both numbers are.
+///
+/// ```
+/// # use advent_of_code::math::gcd;
+/// assert_eq!(gcd(12_u64, 18), 6);
+/// assert_eq!(gcd(-4_i64, 6), 2);
+/// assert_eq!(gcd(0_u32, 7), 7)
+/// ```
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// The least common multiple, or `None` if it overflows.
+///
+/// Divides before multiplying, so only the result has to fit into `T`.
+///
+/// ```
+/// # use advent_of_code::math::lcm;
+/// assert_eq!(lcm(4_u64, 6), Some(12));
+/// assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+/// assert_eq!(lcm(u64::MAX, 2), None)
+/// ```
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (a / gcd(a, b)).checked_mul(b).map(Integer::abs)
+}
+
+/// Returns `(g, x, y)` where `g` is the greatest common divisor and `a * x + b * y = g`.
+pub fn extended_gcd<T: Integer + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `modulus`, if `a` and `modulus` are coprime.
+///
+/// ```
+/// # use advent_of_code::math::mod_inverse;
+/// assert_eq!(mod_inverse(3_i64, 7), Some(5));
+/// assert_eq!(mod_inverse(-3_i64, 7), Some(2));
+/// assert_eq!(mod_inverse(4_i64, 6), None)
+/// ```
+pub fn mod_inverse<T: Integer + Neg<Output = T>>(a: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Solves the system of congruences `x ≡ residue (mod modulus)` with the Chinese Remainder Theorem.
+///
+/// The moduli do not have to be coprime. Returns the smallest non-negative solution together with
+/// the least common multiple of the moduli, every solution being congruent modulo the latter.
+/// Returns `None` if the congruences contradict each other, a modulus is `0`, or the least common multiple overflows.
+///
+/// ```
+/// # use advent_of_code::math::crt;
+/// assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+/// assert_eq!(crt([(0, 4), (1, 6)]), None)
+/// ```
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    let (mut x, mut m) = (0_u64, 1_u64);
+
+    for (residue, modulus) in congruences {
+        if modulus == 0 {
+            return None;
+        }
+
+        // NOTE: the moduli fit into an i128, so do the Bézout coefficients.
+        let (g, p, _) = extended_gcd(i128::from(m), i128::from(modulus));
+        let diff = i128::from(residue % modulus) - i128::from(x);
+        if diff % g != 0 {
+            return None;
+        }
+
+        // solves `m * k ≡ diff (mod modulus)`, `p` being the inverse of `m / g` modulo `n`.
+        let n = modulus / g as u64;
+        let lcm = m.checked_mul(n)?;
+        let k = mul_mod(
+            (diff / g).rem_euclid(i128::from(n)) as u64,
+            p.rem_euclid(i128::from(n)) as u64,
+            n,
+        );
+
+        // NOTE: `x < m` and `k < n`, so the new solution is below their product.
+        x += m * k;
+        m = lcm;
+    }
+
+    Some((x, m))
+}
+
+/// The first step at which all the cycles coincide, where each cycle hits at its offset and then
+/// every period steps after it, e.g. ghosts walking a loop they only enter after a few steps.
+///
+/// Returns `None` if the cycles never coincide or the step overflows.
+///
+/// ```
+/// # use advent_of_code::math::first_coincidence;
+/// // hits at 2, 5, 8, … and at 1, 5, 9, …
+/// assert_eq!(first_coincidence([(2, 3), (1, 4)]), Some(5));
+/// // cycles starting at step 0 coincide at the least common multiple of their periods.
+/// assert_eq!(first_coincidence([(4, 4), (6, 6)]), Some(12))
+/// ```
+pub fn first_coincidence(cycles: impl IntoIterator<Item = (u64, u64)>) -> Option<u64> {
+    let cycles: Vec<(u64, u64)> = cycles.into_iter().collect();
+    let start = cycles.iter().map(|&(offset, _)| offset).max().unwrap_or(0);
+    let (x, m) = crt(cycles)?;
+
+    if x >= start {
+        Some(x)
+    } else {
+        x.checked_add((start - x).div_ceil(m).checked_mul(m)?)
+    }
+}
+
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(modulus)) as u64
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{crt, extended_gcd, first_coincidence, gcd, lcm, mod_inverse};
+
+    #[test]
+    fn computes_gcd_and_lcm() {
+        assert_eq!(gcd(0_u64, 0), 0);
+        assert_eq!(gcd(17_u8, 5), 1);
+        assert_eq!(gcd(-12_i32, -18), 6);
+        assert_eq!(gcd(1_u64 << 40, 3 << 20), 1 << 20);
+
+        assert_eq!(lcm(0_u64, 5), Some(0));
+        assert_eq!(lcm(-4_i64, 6), Some(12));
+        assert_eq!(lcm(1_u64 << 63, 1 << 62), Some(1 << 63));
+        assert_eq!(lcm(u64::MAX - 1, u64::MAX), None);
+
+        // the periods of the ghosts of a day 8 input.
+        let cycles: [u64; 6] = [20_777, 18_673, 13_939, 17_621, 19_199, 12_361];
+        assert_eq!(
+            cycles.into_iter().try_fold(1, lcm),
+            Some(17_972_669_116_327)
+        );
+    }
+
+    #[test]
+    fn computes_bezout_coefficients() {
+        for a in -30_i64..30 {
+            for b in -30_i64..30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(g, gcd(a, b));
+                assert_eq!(a * x + b * y, g, "a = {a}, b = {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn computes_inverses() {
+        for modulus in 1_i64..30 {
+            for a in -30..30 {
+                match mod_inverse(a, modulus) {
+                    Some(inverse) => {
+                        assert!((0..modulus).contains(&inverse));
+                        assert_eq!((a * inverse).rem_euclid(modulus), 1 % modulus);
+                    }
+                    None => assert_ne!(gcd(a, modulus), 1),
+                }
+            }
+        }
+
+        assert_eq!(mod_inverse(3_i64, 0), None);
+        assert_eq!(mod_inverse(3_i64, -7), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(10, 3)]), Some((1, 3)));
+        assert_eq!(crt([(1, 0)]), None);
+
+        // every pair of small congruences agrees with a search over a full period.
+        for m1 in 1_u64..12 {
+            for m2 in 1_u64..12 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let period = lcm(m1, m2).unwrap();
+                        let expected = (0..period).find(|x| x % m1 == r1 && x % m2 == r2);
+                        assert_eq!(crt([(r1, m1), (r2, m2)]), expected.map(|x| (x, period)));
+                    }
+                }
+            }
+        }
+
+        // moduli whose product overflows, but not their least common multiple.
+        let big = 1 << 40;
+        assert_eq!(crt([(5, big * 3), (2, big * 7)]), None);
+        assert_eq!(
+            crt([(big + 5, big * 3), (5, big * 7)]),
+            Some((big * 7 + 5, big * 21))
+        );
+        assert_eq!(crt([(0, u64::MAX), (1, u64::MAX - 1)]), None);
+    }
+
+    #[test]
+    fn finds_first_coincidence() {
+        assert_eq!(first_coincidence([]), Some(0));
+        assert_eq!(first_coincidence([(7, 3)]), Some(7));
+        assert_eq!(first_coincidence([(10, 2), (1, 3)]), Some(10));
+        assert_eq!(first_coincidence([(1, 2), (2, 4)]), None);
+
+        for (o1, p1, o2, p2) in [(3, 5, 11, 7), (9, 4, 2, 6), (1, 1, 20, 9), (6, 6, 9, 9)] {
+            let is_hit =
+                |t: u64, offset: u64, period: u64| t >= offset && (t - offset) % period == 0;
+            let expected = (0..1000).find(|&t| is_hit(t, o1, p1) && is_hit(t, o2, p2));
+            assert_eq!(first_coincidence([(o1, p1), (o2, p2)]), expected);
+        }
+    }
+}
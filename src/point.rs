@@ -0,0 +1,392 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::Pos;
+
+/// A point on a plane, `x` grows to the right and `y` grows downwards like the rows of a [`crate::Grid`].
+///
+/// # Arithmetic
+/// The operators panic on overflow in debug builds like integers do,
+/// the `checked_*` and `wrapping_*` methods never panic.
+///
+/// ```
+/// # use advent_of_code::{Dir4, Point2};
+/// let point: Point2 = "3, 4".parse().unwrap();
+/// assert_eq!(point + Dir4::Up.delta() * 2, Point2::new(3, 2));
+/// assert_eq!(point.manhattan(Point2::ORIGIN), 7);
+/// assert_eq!(Point2::new(i64::MAX, 0).checked_add(Point2::new(1, 0)), None);
+/// assert_eq!(point.to_string(), "3,4")
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+        ))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+        ))
+    }
+
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(rhs)?,
+            self.y.checked_mul(rhs)?,
+        ))
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self::new(self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y))
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::new(self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y))
+    }
+
+    pub fn wrapping_mul(self, rhs: i64) -> Self {
+        Self::new(self.x.wrapping_mul(rhs), self.y.wrapping_mul(rhs))
+    }
+
+    /// Wraps the point into the rectangle from the origin to `size`, e.g. to map a tiled grid onto its tile.
+    pub fn rem_euclid(self, size: Self) -> Self {
+        Self::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+
+    /// The taxicab distance between two points.
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Converts the point to a grid position, returns [`None`] if a coordinate is negative.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+impl From<Pos> for Point2 {
+    /// Converts a grid position, panics if a coordinate does not fit into an [`i64`].
+    fn from((row, col): Pos) -> Self {
+        Self::new(
+            i64::try_from(col).expect("column fits into i64"),
+            i64::try_from(row).expect("row fits into i64"),
+        )
+    }
+}
+
+impl Add for Point2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point2 {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl FromStr for Point2 {
+    type Err = PointFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_coordinates(s)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A point in space, e.g. of a falling brick or a hailstone.
+///
+/// ```
+/// # use advent_of_code::Point3;
+/// let point: Point3 = "1,0,1".parse().unwrap();
+/// assert_eq!(point - Point3::new(0, 0, 1), Point3::new(1, 0, 0));
+/// assert_eq!(point.to_string(), "1,0,1")
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+            self.z.checked_add(rhs.z)?,
+        ))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+            self.z.checked_sub(rhs.z)?,
+        ))
+    }
+
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(rhs)?,
+            self.y.checked_mul(rhs)?,
+            self.z.checked_mul(rhs)?,
+        ))
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.wrapping_add(rhs.x),
+            self.y.wrapping_add(rhs.y),
+            self.z.wrapping_add(rhs.z),
+        )
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.x.wrapping_sub(rhs.x),
+            self.y.wrapping_sub(rhs.y),
+            self.z.wrapping_sub(rhs.z),
+        )
+    }
+
+    pub fn wrapping_mul(self, rhs: i64) -> Self {
+        Self::new(
+            self.x.wrapping_mul(rhs),
+            self.y.wrapping_mul(rhs),
+            self.z.wrapping_mul(rhs),
+        )
+    }
+
+    /// The taxicab distance between two points.
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = PointFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_coordinates(s)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses exactly `N` comma separated coordinates, surrounding whitespace is ignored.
+fn parse_coordinates<const N: usize>(s: &str) -> Result<[i64; N], PointFromStrError> {
+    let coordinates: Vec<i64> = s
+        .split(',')
+        .map(|c| c.trim().parse().map_err(|_| PointFromStrError))
+        .collect::<Result<_, _>>()?;
+
+    coordinates.try_into().map_err(|_| PointFromStrError)
+}
+
+/// An error which can be returned when parsing a [`Point2`] or [`Point3`].
+#[derive(Debug)]
+pub struct PointFromStrError;
+
+impl Error for PointFromStrError {}
+
+impl Display for PointFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting comma separated integer coordinates, e.g. \"3,-4\" or \"1,0,1\"")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Point2, Point3};
+
+    #[test]
+    fn point2_arithmetic() {
+        let mut point = Point2::new(3, -4);
+
+        assert_eq!(point + Point2::new(1, 1), Point2::new(4, -3));
+        assert_eq!(point - Point2::new(1, 1), Point2::new(2, -5));
+        assert_eq!(point * 3, Point2::new(9, -12));
+        assert_eq!(-point, Point2::new(-3, 4));
+        point += Point2::new(1, 0);
+        point -= Point2::new(0, 1);
+        assert_eq!(point, Point2::new(4, -5));
+        assert_eq!(point.manhattan(Point2::ORIGIN), 9);
+        assert_eq!(
+            Point2::new(-1, 7).rem_euclid(Point2::new(5, 5)),
+            Point2::new(4, 2)
+        );
+    }
+
+    #[test]
+    fn point2_checked_and_wrapping() {
+        let max = Point2::new(i64::MAX, 0);
+        let one = Point2::new(1, 1);
+
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(Point2::new(i64::MIN, 0).checked_sub(one), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(one.checked_add(one), Some(Point2::new(2, 2)));
+
+        assert_eq!(max.wrapping_add(one), Point2::new(i64::MIN, 1));
+        assert_eq!(
+            Point2::new(i64::MIN, 0).wrapping_sub(one),
+            Point2::new(i64::MAX, -1)
+        );
+        assert_eq!(max.wrapping_mul(2), Point2::new(-2, 0));
+    }
+
+    #[test]
+    fn point2_grid_positions() {
+        assert_eq!(Point2::from((2, 5)), Point2::new(5, 2));
+        assert_eq!(Point2::new(5, 2).to_pos(), Some((2, 5)));
+        assert_eq!(Point2::new(-1, 2).to_pos(), None);
+    }
+
+    #[test]
+    fn point3_arithmetic() {
+        let point = Point3::new(1, 2, 3);
+
+        assert_eq!(point + point, point * 2);
+        assert_eq!(point - point, Point3::ORIGIN);
+        assert_eq!(-point, Point3::new(-1, -2, -3));
+        assert_eq!(point.manhattan(Point3::new(-1, 2, 0)), 5);
+        assert_eq!(Point3::new(0, 0, i64::MAX).checked_add(point), None);
+        assert_eq!(
+            Point3::new(0, 0, i64::MAX).wrapping_add(point),
+            Point3::new(1, 2, i64::MIN + 2)
+        );
+    }
+
+    #[test]
+    fn parses_points() {
+        assert_eq!("3,-4".parse::<Point2>().unwrap(), Point2::new(3, -4));
+        assert_eq!(" 19, 13 ".parse::<Point2>().unwrap(), Point2::new(19, 13));
+        assert_eq!(
+            "19, 13, 30".parse::<Point3>().unwrap(),
+            Point3::new(19, 13, 30)
+        );
+
+        assert!("1".parse::<Point2>().is_err());
+        assert!("1,2,3".parse::<Point2>().is_err());
+        assert!("1,a".parse::<Point2>().is_err());
+        assert!("1,2".parse::<Point3>().is_err());
+    }
+}
@@ -0,0 +1,77 @@
+/// Registry of all solutions, compiled into the library so they can be run without spawning their binaries.
+/// The sources in `src/bin` are included as modules, each binary is still generated by [`crate::solution!`].
+use crate::template::{report::PartReport, runner::RunOptions};
+use crate::{PuzzleId, Year};
+
+/// A puzzle's solution, as registered by [`crate::solution!`].
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    /// Solves part one of the input, including the shared parse step if the day has one.
+    pub part_one: fn(&str) -> Option<String>,
+    /// Solves part two of the input, including the shared parse step if the day has one.
+    pub part_two: fn(&str) -> Option<String>,
+    /// Runs, prints and optionally benches every step of the puzzle like its binary does.
+    pub run: fn(&str, &RunOptions) -> Vec<PartReport>,
+}
+
+/// Returns the registered solution of a puzzle, if any.
+#[must_use]
+pub fn get(puzzle: PuzzleId) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.puzzle == puzzle)
+}
+
+/// Returns every registered solution of a year, in day order.
+pub fn of_year(year: Year) -> impl Iterator<Item = &'static Solution> {
+    SOLUTIONS.iter().filter(move |s| s.puzzle.year == year)
+}
+
+/// Returns every registered solution, ordered by year and day.
+#[must_use]
+pub fn all() -> &'static [Solution] {
+    SOLUTIONS
+}
+
+macro_rules! register {
+    ($($(#[$attr:meta])* $module:ident => $path:literal,)*) => {
+        $(
+            // already linted when compiled as a binary.
+            $(#[$attr])*
+            #[path = $path]
+            #[allow(warnings, clippy::all, clippy::pedantic)]
+            mod $module;
+        )*
+
+        static SOLUTIONS: &[Solution] = &[$($(#[$attr])* $module::SOLUTION,)*];
+    };
+}
+
+// NOTE: `scaffold` inserts new puzzles into this list, keeping it ordered by year and day.
+// Days with heavy dependencies are gated behind the feature of that dependency, like in `Cargo.toml`.
+register! {
+    y2023_day01 => "bin/2023_01.rs",
+    y2023_day02 => "bin/2023_02.rs",
+    y2023_day03 => "bin/2023_03.rs",
+    y2023_day04 => "bin/2023_04.rs",
+    y2023_day05 => "bin/2023_05.rs",
+    y2023_day06 => "bin/2023_06.rs",
+    y2023_day07 => "bin/2023_07.rs",
+    y2023_day08 => "bin/2023_08.rs",
+    y2023_day09 => "bin/2023_09.rs",
+    y2023_day10 => "bin/2023_10.rs",
+    y2023_day11 => "bin/2023_11.rs",
+    y2023_day12 => "bin/2023_12.rs",
+    y2023_day13 => "bin/2023_13.rs",
+    y2023_day14 => "bin/2023_14.rs",
+    y2023_day15 => "bin/2023_15.rs",
+    y2023_day16 => "bin/2023_16.rs",
+    y2023_day17 => "bin/2023_17.rs",
+    y2023_day18 => "bin/2023_18.rs",
+    y2023_day19 => "bin/2023_19.rs",
+    y2023_day20 => "bin/2023_20.rs",
+    y2023_day21 => "bin/2023_21.rs",
+    y2023_day22 => "bin/2023_22.rs",
+    y2023_day23 => "bin/2023_23.rs",
+    #[cfg(feature = "z3")] y2023_day24 => "bin/2023_24.rs",
+    y2023_day25 => "bin/2023_25.rs",
+}
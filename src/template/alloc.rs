@@ -0,0 +1,224 @@
+/// Module that counts the heap allocations of solution parts.
+/// The counting allocator is only installed with the `alloc_stats` feature, e.g. `cargo solve 12 --alloc`,
+/// without it [`measure`] reports nothing and allocations are not slowed down.
+///
+/// Allocations are counted per thread, so threads spawned by a solution itself are not included.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Heap usage of a single run of a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested by all allocations.
+    pub bytes: u64,
+    /// Highest number of bytes that were live at the same time, on top of what was live before the run.
+    pub peak_bytes: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} total, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // const initialized, so that accessing it never allocates from within the allocator.
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn record(allocated: usize, freed: usize) {
+    // the counters are gone while a thread shuts down, those allocations are not counted.
+    let _ = COUNTERS.try_with(|cell| {
+        let mut counters = cell.get();
+        if allocated > 0 {
+            counters.allocations += 1;
+            counters.bytes += allocated as u64;
+        }
+        counters.live += allocated as i64 - freed as i64;
+        counters.peak = counters.peak.max(counters.live);
+        cell.set(counters);
+    });
+}
+
+/// A global allocator that forwards to the system allocator and counts allocations of the current thread.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc_stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Whether allocations are counted, i.e. the `alloc_stats` feature is enabled.
+#[must_use]
+pub fn is_enabled() -> bool {
+    cfg!(feature = "alloc_stats")
+}
+
+/// Runs `func` and counts its allocations on the current thread.
+/// Returns [`None`] as stats if the counting allocator is not installed.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_enabled() {
+        return (func(), None);
+    }
+
+    let before = COUNTERS.with(Cell::get);
+    // tracks the peak of this run only.
+    COUNTERS.with(|cell| {
+        cell.set(Counters {
+            peak: before.live,
+            ..before
+        });
+    });
+
+    let result = func();
+
+    let after = COUNTERS.with(Cell::get);
+    // keeps the peak of an enclosing measurement intact.
+    COUNTERS.with(|cell| {
+        cell.set(Counters {
+            peak: before.peak.max(after.peak),
+            ..after
+        });
+    });
+
+    let stats = AllocStats {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak_bytes: u64::try_from(after.peak - before.live).unwrap_or_default(),
+    };
+
+    (result, Some(stats))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::alloc::{GlobalAlloc, Layout};
+
+    use super::{format_bytes, measure, record, AllocStats, CountingAllocator, COUNTERS};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+
+        let stats = AllocStats {
+            allocations: 3,
+            bytes: 2048,
+            peak_bytes: 100,
+        };
+        assert_eq!(stats.to_string(), "3 allocs, 2.0 KiB total, 100 B peak");
+    }
+
+    #[test]
+    fn counts_allocations_of_thread() {
+        let before = COUNTERS.with(std::cell::Cell::get);
+
+        // calls the allocator directly, as it is not installed without the feature.
+        unsafe {
+            let layout = Layout::from_size_align(64, 8).unwrap();
+            let ptr = CountingAllocator.alloc(layout);
+            let ptr = CountingAllocator.realloc(ptr, layout, 256);
+            CountingAllocator.dealloc(ptr, Layout::from_size_align(256, 8).unwrap());
+        }
+        record(32, 0);
+
+        let after = COUNTERS.with(std::cell::Cell::get);
+        assert_eq!(after.allocations - before.allocations, 3);
+        assert_eq!(after.bytes - before.bytes, 64 + 256 + 32);
+        assert_eq!(after.live - before.live, 32);
+        assert!(after.peak - before.live >= 256);
+    }
+
+    #[test]
+    fn measures_only_with_feature() {
+        let (result, stats) = measure(|| vec![1_u8; 100].len());
+        assert_eq!(result, 100);
+
+        if cfg!(feature = "alloc_stats") {
+            let stats = stats.unwrap();
+            assert!(stats.allocations >= 1 && stats.bytes >= 100 && stats.peak_bytes >= 100);
+        } else {
+            assert_eq!(stats, None);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+/// Module that stores the expected answers of solved days in `data/YYYY/answers/DD.toml`.
+/// These are used by `verify` to catch refactors that change the result of a solution.
+use std::{fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answers file: {e}"),
+            Error::IO(e) => write!(f, "could not access answers file: {e}"),
+        }
+    }
+}
+
+/// The expected answers of a day for the real input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_1 = Some(answer.into()),
+            2 => self.part_2 = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.part_1.is_none() && self.part_2.is_none()
+    }
+
+    /// Whether the answers of both parts are known, i.e. the day is solved.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.part_1.is_some() && self.part_2.is_some()
+    }
+
+    fn parse(s: &str) -> Result<Self, Error> {
+        toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    fn serialize(&self) -> Result<String, Error> {
+        toml::to_string(self).map_err(|e| Error::Parser(e.to_string()))
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("answers", "toml")
+}
+
+/// Reads the stored answers of a puzzle, a missing file yields empty answers.
+pub fn read(puzzle: PuzzleId) -> Result<Answers, Error> {
+    let path = get_path(puzzle);
+
+    if !Path::new(&path).exists() {
+        return Ok(Answers::default());
+    }
+
+    Answers::parse(&fs::read_to_string(path)?)
+}
+
+/// Stores the answer of a single part, keeping the answer of the other part intact.
+pub fn record(puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = read(puzzle)?;
+    answers.set(part, answer);
+
+    let path = get_path(puzzle);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, answers.serialize()?)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answers;
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = Answers::parse("part_1 = \"35\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("35"));
+        assert_eq!(answers.get(2), None);
+        assert_eq!(answers.is_empty(), false);
+    }
+
+    #[test]
+    fn round_trips_answers() {
+        let mut answers = Answers::default();
+        answers.set(2, "46");
+        answers.set(1, "35");
+
+        let s = answers.serialize().unwrap();
+        assert_eq!(s, "part_1 = \"35\"\npart_2 = \"46\"\n");
+        assert_eq!(Answers::parse(&s).unwrap(), answers);
+    }
+
+    #[test]
+    fn errors_on_malformed_file() {
+        assert!(Answers::parse("part_1 = 35").is_err());
+    }
+}
@@ -0,0 +1,454 @@
+/// Client for the Advent of Code website, to download inputs and puzzles and to submit answers.
+///
+/// The session cookie is read from the `AOC_SESSION` environment variable or the file `~/.adventofcode.session`.
+/// The server can be changed with `AOC_BASE_URL`.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::PuzzleId;
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static SESSION_FILE: &str = ".adventofcode.session";
+static USER_AGENT: &str = "github.com/M315/Advent_of_code_2023 (advent_of_code template)";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    /// The server responded with an error status.
+    Http {
+        status: u16,
+        body: String,
+    },
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocClientError::Http {
+                status,
+                body: response.into_string().unwrap_or_default().trim().into(),
+            },
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found, set AOC_SESSION or write it to \"~/{SESSION_FILE}\"."
+            ),
+            AocClientError::Http { status, body } => {
+                write!(f, "server responded with status {status}: {body}")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "could not access file system: {e}"),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.trim().into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// Creates a client configured by the environment, see the module documentation.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = get_session().ok_or(AocClientError::MissingSession)?;
+        Ok(Self::new(&base_url, &session))
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        self.agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string()))
+    }
+
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Fetches the descriptions of a puzzle, one `<article>` per unlocked part.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let page = self.get(&self.day_url(puzzle))?;
+        Ok(extract_articles(&page).join("\n\n"))
+    }
+
+    /// Submits an answer and returns the message of the response as plain text.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let page = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(puzzle)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string()))?;
+
+        Ok(extract_articles(&page)
+            .iter()
+            .map(|article| html_to_text(article))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+/// The link to a puzzle on the website, which is not affected by `AOC_BASE_URL`.
+#[must_use]
+pub fn get_puzzle_url(puzzle: PuzzleId) -> String {
+    format!(
+        "{DEFAULT_BASE_URL}/{}/day/{}",
+        puzzle.year,
+        puzzle.day.into_inner()
+    )
+}
+
+#[must_use]
+pub fn get_input_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("inputs", "txt")
+}
+
+/// The description is stored as the HTML of its `<article>` elements, which titles and examples are read from.
+#[must_use]
+pub fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("puzzles", "html")
+}
+
+/// Downloads the input and description of a puzzle to `data/YYYY/inputs` and `data/YYYY/puzzles`.
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let input = client.get_input(puzzle)?;
+    let description = client.get_puzzle(puzzle)?;
+
+    write_file(&input_path, &input)?;
+    write_file(&puzzle_path, &description)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Downloads the description of a puzzle to `data/YYYY/puzzles` and prints it as plain text.
+pub fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.get_puzzle(puzzle)?;
+
+    write_file(&get_puzzle_path(puzzle), &description)?;
+
+    println!("{}", html_to_text(&description));
+    Ok(())
+}
+
+fn write_file(path: &str, content: &str) -> io::Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content)
+}
+
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session).filter(|s| !s.trim().is_empty());
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let session = fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)).ok()?;
+    Some(session.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the inner HTML of every `<article>` element of a page.
+fn extract_articles(page: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = page;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(len) = rest[content_start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(rest[content_start..content_start + len].trim());
+        rest = &rest[content_start + len..];
+    }
+
+    articles
+}
+
+/// Decodes the HTML entities that occur in puzzle descriptions.
+#[must_use]
+pub fn decode_entities(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Reads the title from the first heading of a puzzle description, e.g. `Mirage Maintenance`
+/// from `<h2>--- Day 9: Mirage Maintenance ---</h2>`.
+#[must_use]
+pub fn puzzle_title(puzzle: &str) -> Option<String> {
+    let start = puzzle.find("<h2")?;
+    let heading = &puzzle[start..];
+    let heading = &heading[heading.find('>')? + 1..heading.find("</h2>")?];
+
+    let (_, title) = heading
+        .trim_matches(|c| c == '-' || c == ' ')
+        .split_once(": ")?;
+    Some(decode_entities(title.trim())).filter(|t| !t.is_empty())
+}
+
+/// Converts HTML to plain text by dropping tags, breaking lines after block elements and decoding entities.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = rest[start + 1..start + len].trim_start_matches('/');
+        let name = tag.split_whitespace().next().unwrap_or_default();
+
+        match name {
+            "p" | "pre" | "h2" | "ul" if rest.as_bytes()[start + 1] == b'/' => {
+                text.push_str("\n\n")
+            }
+            "li" if rest.as_bytes()[start + 1] != b'/' => text.push_str("- "),
+            "li" | "br" => text.push('\n'),
+            _ => {}
+        }
+
+        rest = &rest[start + len + 1..];
+    }
+    text.push_str(rest);
+
+    let mut text = decode_entities(&text);
+
+    // nested block elements end up with more than one blank line.
+    while text.contains("\n\n\n") {
+        text = text.replace("\n\n\n", "\n\n");
+    }
+
+    text.trim().to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{extract_articles, html_to_text, puzzle_title, AocClient, AocClientError};
+    use crate::{day, year, PuzzleId};
+
+    /// A request as received by [`mock_server`].
+    struct Request {
+        head: String,
+        body: String,
+    }
+
+    /// Serves a single request with the given status and body, returns the request once it was answered.
+    fn mock_server(status: u16, body: &'static str) -> (String, JoinHandle<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+
+            let content_length = head
+                .lines()
+                .find_map(|l| {
+                    l.to_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|x| x.trim().parse().unwrap())
+                })
+                .unwrap_or(0);
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            let response = format!(
+                "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+            Request {
+                head,
+                body: String::from_utf8(request_body).unwrap(),
+            }
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn downloads_input_with_session() {
+        let (url, server) = mock_server(200, "1 2 3\n");
+        let client = AocClient::new(&format!("{url}/"), "abc\n");
+
+        assert_eq!(
+            client
+                .get_input(PuzzleId::new(year!(2023), day!(7)))
+                .unwrap(),
+            "1 2 3\n"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.head.starts_with("GET /2023/day/7/input HTTP/1.1"));
+        assert!(request.head.contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn extracts_puzzle_articles() {
+        let (url, server) = mock_server(
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 7 ---</h2></article><p>x</p><article class=\"day-desc\"><p>Part two</p></article></main>",
+        );
+        let client = AocClient::new(&url, "abc");
+
+        assert_eq!(
+            client
+                .get_puzzle(PuzzleId::new(year!(2023), day!(7)))
+                .unwrap(),
+            "<h2>--- Day 7 ---</h2>\n\n<p>Part two</p>"
+        );
+        assert!(server
+            .join()
+            .unwrap()
+            .head
+            .starts_with("GET /2023/day/7 HTTP/1.1"));
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (url, server) = mock_server(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let client = AocClient::new(&url, "abc");
+
+        let message = client
+            .submit(PuzzleId::new(year!(2023), day!(7)), 2, "5905")
+            .unwrap();
+        assert_eq!(
+            message,
+            "That's the right answer! You are one gold star closer."
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.head.starts_with("POST /2023/day/7/answer HTTP/1.1"));
+        assert_eq!(request.body, "level=2&answer=5905");
+    }
+
+    #[test]
+    fn reports_http_errors() {
+        let (url, server) = mock_server(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        );
+        let client = AocClient::new(&url, "expired");
+
+        match client.get_input(PuzzleId::new(year!(2023), day!(7))) {
+            Err(AocClientError::Http { status, body }) => {
+                assert_eq!(status, 400);
+                assert!(body.ends_with("log in to get your puzzle input."));
+            }
+            _ => panic!("expected an HTTP error"),
+        }
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn converts_html_to_text() {
+        let html = "<h2>--- Day 1 ---</h2><p>Sum <code>1 &lt; 2</code> &amp; more:</p><ul><li>one</li><li>two</li></ul><pre><code>a\nb\n</code></pre>";
+        assert_eq!(
+            html_to_text(html),
+            "--- Day 1 ---\n\nSum 1 < 2 & more:\n\n- one\n- two\n\na\nb"
+        );
+        assert_eq!(extract_articles("<p>no articles</p>"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn reads_puzzle_title() {
+        let puzzle = "<h2>--- Day 9: Mirage &amp; Maintenance ---</h2><p>x</p><h2 id=\"part2\">--- Part Two ---</h2>";
+        assert_eq!(
+            puzzle_title(puzzle).as_deref(),
+            Some("Mirage & Maintenance")
+        );
+        assert_eq!(puzzle_title("<h2>--- Day 7 ---</h2>"), None);
+        assert_eq!(puzzle_title("<p>no heading</p>"), None);
+    }
+}
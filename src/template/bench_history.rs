@@ -0,0 +1,314 @@
+/// Module that keeps a history of benchmark runs, so timings can be compared across commits.
+/// Every timed release run of `all` appends one line of JSON to the history file of its year.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{
+    readme_benchmarks::Timings,
+    report::{DurationReport, PARSE_PART},
+};
+use crate::Year;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// The timings of a single day within a benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayEntry {
+    pub day: u8,
+    #[serde(default)]
+    pub parse: Option<DurationReport>,
+    pub part_1: Option<DurationReport>,
+    pub part_2: Option<DurationReport>,
+}
+
+impl DayEntry {
+    fn part(&self, part: u8) -> Option<&DurationReport> {
+        match part {
+            PARSE_PART => self.parse.as_ref(),
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// A single benchmark run, keyed by the commit it was run on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub commit: String,
+    /// Whether the working tree had uncommitted changes during the run.
+    pub dirty: bool,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub days: Vec<DayEntry>,
+}
+
+impl HistoryEntry {
+    #[must_use]
+    pub fn new(timings: &[Timings]) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        let (commit, dirty) = git_revision();
+
+        Self {
+            commit,
+            dirty,
+            timestamp,
+            days: timings
+                .iter()
+                .map(|t| DayEntry {
+                    day: t.puzzle.day.into_inner(),
+                    parse: t.parse.as_ref().map(DurationReport::from),
+                    part_1: t.part_1.as_ref().map(DurationReport::from),
+                    part_2: t.part_2.as_ref().map(DurationReport::from),
+                })
+                .collect(),
+        }
+    }
+
+    /// A short label to identify the run on the console.
+    #[must_use]
+    pub fn label(&self) -> String {
+        if self.dirty {
+            format!("{} (dirty)", self.commit)
+        } else {
+            self.commit.clone()
+        }
+    }
+}
+
+/// A part whose median time changed beyond the threshold between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub day: u8,
+    /// The part number, [`PARSE_PART`] for the shared parse step.
+    pub part: u8,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    /// Relative change of the median in percent, positive if the part got slower.
+    pub percent: f64,
+}
+
+impl Change {
+    #[must_use]
+    pub fn is_regression(&self) -> bool {
+        self.percent > 0.0
+    }
+}
+
+/// Returns the short hash of `HEAD` and whether the working tree is dirty.
+fn git_revision() -> (String, bool) {
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map_or_else(
+            || "unknown".into(),
+            |o| String::from_utf8_lossy(&o.stdout).trim().to_string(),
+        );
+
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|o| !o.stdout.is_empty());
+
+    (commit, dirty)
+}
+
+fn parse_history(s: &str) -> Result<Vec<HistoryEntry>, Error> {
+    s.lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str(l).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+#[must_use]
+pub fn get_path(year: Year) -> String {
+    format!("data/{year}/bench_history.jsonl")
+}
+
+/// Reads all recorded runs of a year, oldest first.
+pub fn read(year: Year) -> Result<Vec<HistoryEntry>, Error> {
+    let path = get_path(year);
+
+    if !Path::new(&path).exists() {
+        return Ok(vec![]);
+    }
+
+    parse_history(&fs::read_to_string(path)?)
+}
+
+/// Appends a run to the history file of a year.
+pub fn append(year: Year, entry: &HistoryEntry) -> Result<(), Error> {
+    let line = serde_json::to_string(entry).map_err(|e| Error::Parser(e.to_string()))?;
+
+    let path = get_path(year);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+/// Finds the run to compare the latest run against.
+/// This is the most recent earlier run on a commit starting with `baseline`, or the previous run if no baseline is given.
+#[must_use]
+pub fn find_baseline<'a>(
+    history: &'a [HistoryEntry],
+    baseline: Option<&str>,
+) -> Option<&'a HistoryEntry> {
+    let (_, previous) = history.split_last()?;
+
+    match baseline {
+        Some(commit) => previous.iter().rev().find(|e| e.commit.starts_with(commit)),
+        None => previous.last(),
+    }
+}
+
+/// Lists every part whose median changed by more than `threshold` percent, in day order.
+#[must_use]
+pub fn compare(baseline: &HistoryEntry, current: &HistoryEntry, threshold: f64) -> Vec<Change> {
+    let mut changes = vec![];
+
+    for day in &current.days {
+        let Some(baseline_day) = baseline.days.iter().find(|d| d.day == day.day) else {
+            continue;
+        };
+
+        for part in [PARSE_PART, 1, 2] {
+            let (Some(before), Some(after)) = (baseline_day.part(part), day.part(part)) else {
+                continue;
+            };
+
+            if before.median_ns == 0 {
+                continue;
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let percent = (after.median_ns as f64 - before.median_ns as f64)
+                / before.median_ns as f64
+                * 100.0;
+
+            if percent.abs() > threshold {
+                changes.push(Change {
+                    day: day.day,
+                    part,
+                    baseline_ns: before.median_ns,
+                    current_ns: after.median_ns,
+                    percent,
+                });
+            }
+        }
+    }
+
+    changes
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, find_baseline, parse_history, DayEntry, HistoryEntry};
+    use crate::template::report::DurationReport;
+
+    fn median(ns: u64) -> Option<DurationReport> {
+        Some(DurationReport {
+            min_ns: ns,
+            median_ns: ns,
+            p95_ns: ns,
+            std_dev_ns: 0,
+            outliers: 0,
+        })
+    }
+
+    fn entry(commit: &str, days: &[(u8, u64, u64)]) -> HistoryEntry {
+        HistoryEntry {
+            commit: commit.into(),
+            dirty: false,
+            timestamp: 1_700_000_000,
+            days: days
+                .iter()
+                .map(|&(day, p1, p2)| DayEntry {
+                    day,
+                    parse: None,
+                    part_1: median(p1),
+                    part_2: median(p2),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_history_lines() {
+        let line = serde_json::to_string(&entry("abc1234", &[(1, 10, 20)])).unwrap();
+        let history = parse_history(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1], entry("abc1234", &[(1, 10, 20)]));
+        assert!(parse_history("not json").is_err());
+    }
+
+    #[test]
+    fn finds_previous_run_by_default() {
+        let history = vec![entry("aaa", &[]), entry("bbb", &[]), entry("ccc", &[])];
+        assert_eq!(find_baseline(&history, None).unwrap().commit, "bbb");
+        assert_eq!(find_baseline(&history, Some("aa")).unwrap().commit, "aaa");
+        assert_eq!(find_baseline(&history, Some("ccc")), None);
+        assert_eq!(find_baseline(&history[..1], None), None);
+    }
+
+    #[test]
+    fn reads_entries_without_parse_step() {
+        let history = parse_history(
+            r#"{"commit":"abc","dirty":false,"timestamp":1,"days":[{"day":1,"part_1":null,"part_2":null}]}"#,
+        )
+        .unwrap();
+        assert_eq!(history[0].days[0].parse, None);
+    }
+
+    #[test]
+    fn flags_changes_beyond_threshold() {
+        let baseline = entry("aaa", &[(1, 100, 100), (2, 100, 100), (3, 100, 100)]);
+        let current = entry("bbb", &[(1, 105, 200), (2, 50, 100), (4, 1, 1)]);
+
+        let changes = compare(&baseline, &current, 10.0);
+        assert_eq!(changes.len(), 2);
+
+        assert_eq!((changes[0].day, changes[0].part), (1, 2));
+        assert_eq!(changes[0].is_regression(), true);
+        assert_eq!(changes[0].percent, 100.0);
+
+        assert_eq!((changes[1].day, changes[1].part), (2, 1));
+        assert_eq!(changes[1].is_regression(), false);
+    }
+}
@@ -0,0 +1,367 @@
+/// Module that renders the benchmark history to SVG charts for the readme.
+/// The charts are plain SVG markup written by hand, so no plotting tool is needed.
+use std::{fmt::Write as _, fs, io, time::Duration};
+
+use crate::template::{
+    bench_history::{DayEntry, HistoryEntry},
+    report::DurationReport,
+};
+use crate::Year;
+
+static ASSETS_DIR: &str = ".assets";
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 320.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 40.0;
+
+/// Colors of the parse step and both parts, the parts use the colors of the silver and gold stars.
+const SERIES: [(&str, &str); 3] = [
+    ("Parse", "#b0b0b0"),
+    ("Part 1", "#9999cc"),
+    ("Part 2", "#e6b800"),
+];
+
+fn plot_width() -> f64 {
+    WIDTH - MARGIN_LEFT - MARGIN_RIGHT
+}
+
+fn plot_height() -> f64 {
+    HEIGHT - MARGIN_TOP - MARGIN_BOTTOM
+}
+
+/// Path of the bar chart of a year, relative to the repository root.
+#[must_use]
+pub fn get_bar_chart_path(year: Year) -> String {
+    format!("{ASSETS_DIR}/benchmarks_{year}.svg")
+}
+
+/// Path of the trend chart of a year, relative to the repository root.
+#[must_use]
+pub fn get_trend_chart_path(year: Year) -> String {
+    format!("{ASSETS_DIR}/benchmark_trend_{year}.svg")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos(report: &DurationReport) -> f64 {
+    report.median_ns.max(1) as f64
+}
+
+fn format_nanos(ns: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(ns.round() as u64);
+    format!("{duration:.0?}")
+}
+
+fn open_svg(title: &str) -> String {
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" font-family="sans-serif" font-size="11">
+<rect width="100%" height="100%" fill="#ffffff"/>
+<text x="{}" y="20" font-size="14" font-weight="bold" text-anchor="middle">{}</text>
+"##,
+        WIDTH / 2.0,
+        escape(title)
+    )
+}
+
+fn horizontal_grid_line(svg: &mut String, y: f64, label: &str) {
+    let _ = writeln!(
+        svg,
+        r##"<line x1="{MARGIN_LEFT}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="#e5e5e5"/><text x="{:.1}" y="{:.1}" text-anchor="end">{label}</text>"##,
+        WIDTH - MARGIN_RIGHT,
+        MARGIN_LEFT - 6.0,
+        y + 4.0
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A logarithmic axis that spans whole decades of nanoseconds.
+struct LogScale {
+    min_exp: i32,
+    max_exp: i32,
+}
+
+impl LogScale {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(min_ns: f64, max_ns: f64) -> Self {
+        let min_exp = min_ns.log10().floor() as i32;
+        let max_exp = (max_ns.log10().ceil() as i32).max(min_exp + 1);
+        Self { min_exp, max_exp }
+    }
+
+    /// The vertical position of a value, from the bottom of the plot.
+    fn y(&self, ns: f64) -> f64 {
+        let span = f64::from(self.max_exp - self.min_exp);
+        let share = (ns.log10() - f64::from(self.min_exp)) / span;
+        HEIGHT - MARGIN_BOTTOM - share.clamp(0.0, 1.0) * plot_height()
+    }
+
+    fn ticks(&self) -> impl Iterator<Item = f64> {
+        (self.min_exp..=self.max_exp).map(|exp| 10_f64.powi(exp))
+    }
+}
+
+/// Renders the median time of the parse step and both parts of every day as grouped bars on a log scale.
+#[must_use]
+pub fn bar_chart(title: &str, days: &[DayEntry]) -> String {
+    let values: Vec<f64> = days
+        .iter()
+        .flat_map(|d| [&d.parse, &d.part_1, &d.part_2])
+        .filter_map(|r| r.as_ref().map(nanos))
+        .collect();
+
+    let mut svg = open_svg(title);
+
+    if values.is_empty() {
+        svg.push_str("</svg>\n");
+        return svg;
+    }
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(0.0, f64::max);
+    let scale = LogScale::new(min, max);
+
+    for tick in scale.ticks() {
+        horizontal_grid_line(&mut svg, scale.y(tick), &format_nanos(tick));
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let group_width = plot_width() / days.len() as f64;
+    let bar_width = (group_width * 0.8 / 3.0).min(12.0);
+    let bottom = HEIGHT - MARGIN_BOTTOM;
+
+    for (i, day) in days.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let center = MARGIN_LEFT + group_width * (i as f64 + 0.5);
+        let reports = [&day.parse, &day.part_1, &day.part_2];
+
+        for (j, (report, (name, color))) in reports.iter().zip(SERIES).enumerate() {
+            let Some(report) = report else {
+                continue;
+            };
+            let value = nanos(report);
+            let y = scale.y(value);
+            #[allow(clippy::cast_precision_loss)]
+            let x = center + bar_width * (j as f64 - 1.5);
+
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{x:.1}" y="{y:.1}" width="{bar_width:.1}" height="{:.1}" fill="{color}"><title>Day {} {name}: {}</title></rect>"#,
+                bottom - y,
+                day.day,
+                format_nanos(value)
+            );
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="{center:.1}" y="{:.1}" text-anchor="middle">{}</text>"#,
+            bottom + 16.0,
+            day.day
+        );
+    }
+
+    for (i, (name, color)) in SERIES.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = MARGIN_LEFT + 70.0 * i as f64;
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x:.1}" y="{:.1}" width="10" height="10" fill="{color}"/><text x="{:.1}" y="{:.1}">{name}</text>"#,
+            HEIGHT - 14.0,
+            x + 14.0,
+            HEIGHT - 5.0
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Sum of the median times of every step of a run.
+fn total_nanos(entry: &HistoryEntry) -> f64 {
+    entry
+        .days
+        .iter()
+        .flat_map(|d| [&d.parse, &d.part_1, &d.part_2])
+        .filter_map(|r| r.as_ref().map(nanos))
+        .sum()
+}
+
+/// Renders the total run time of every run in the history as a line, oldest run first.
+#[must_use]
+pub fn trend_chart(title: &str, history: &[HistoryEntry]) -> String {
+    let mut svg = open_svg(title);
+
+    let totals: Vec<f64> = history.iter().map(total_nanos).collect();
+    let max = totals.iter().copied().fold(0.0, f64::max);
+
+    if totals.is_empty() || max <= 0.0 {
+        svg.push_str("</svg>\n");
+        return svg;
+    }
+
+    // leaves some room above the slowest run.
+    let top = max * 1.1;
+    let bottom = HEIGHT - MARGIN_BOTTOM;
+    let y = |ns: f64| bottom - ns / top * plot_height();
+
+    for i in 0..=4 {
+        let tick = top * f64::from(i) / 4.0;
+        horizontal_grid_line(&mut svg, y(tick), &format_nanos(tick));
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let x = |i: usize| match totals.len() {
+        1 => MARGIN_LEFT + plot_width() / 2.0,
+        n => MARGIN_LEFT + plot_width() * i as f64 / (n - 1) as f64,
+    };
+
+    let points: Vec<String> = totals
+        .iter()
+        .enumerate()
+        .map(|(i, &total)| format!("{:.1},{:.1}", x(i), y(total)))
+        .collect();
+
+    let _ = writeln!(
+        svg,
+        r##"<polyline points="{}" fill="none" stroke="#e6b800" stroke-width="2"/>"##,
+        points.join(" ")
+    );
+
+    for (i, (entry, &total)) in history.iter().zip(&totals).enumerate() {
+        let _ = writeln!(
+            svg,
+            r##"<circle cx="{:.1}" cy="{:.1}" r="3" fill="#e6b800"><title>{}: {}</title></circle>"##,
+            x(i),
+            y(total),
+            escape(&entry.label()),
+            format_nanos(total)
+        );
+    }
+
+    let _ = writeln!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}" text-anchor="middle">{} run(s), latest: {}</text>"#,
+        WIDTH / 2.0,
+        HEIGHT - 12.0,
+        totals.len(),
+        format_nanos(totals[totals.len() - 1])
+    );
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders both charts of a year from its benchmark history and writes them to `.assets/`.
+/// Returns the paths of the written charts, nothing is written for an empty history.
+pub fn write(year: Year, history: &[HistoryEntry]) -> io::Result<Vec<String>> {
+    let Some(latest) = history.last() else {
+        return Ok(vec![]);
+    };
+
+    fs::create_dir_all(ASSETS_DIR)?;
+
+    let bar_path = get_bar_chart_path(year);
+    let bar = bar_chart(&format!("{year} run time per part"), &latest.days);
+    fs::write(&bar_path, bar)?;
+
+    let trend_path = get_trend_chart_path(year);
+    let trend = trend_chart(&format!("{year} total run time"), history);
+    fs::write(&trend_path, trend)?;
+
+    Ok(vec![bar_path, trend_path])
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bar_chart, escape, trend_chart, LogScale, HEIGHT, MARGIN_BOTTOM, MARGIN_TOP};
+    use crate::template::{
+        bench_history::{DayEntry, HistoryEntry},
+        report::DurationReport,
+    };
+
+    fn report(median_ns: u64) -> Option<DurationReport> {
+        Some(DurationReport {
+            min_ns: median_ns,
+            median_ns,
+            p95_ns: median_ns,
+            std_dev_ns: 0,
+            outliers: 0,
+        })
+    }
+
+    fn entry(commit: &str, days: &[(u8, u64, u64)]) -> HistoryEntry {
+        HistoryEntry {
+            commit: commit.into(),
+            dirty: false,
+            timestamp: 0,
+            days: days
+                .iter()
+                .map(|&(day, p1, p2)| DayEntry {
+                    day,
+                    parse: None,
+                    part_1: report(p1),
+                    part_2: report(p2),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn log_scale_spans_decades() {
+        let scale = LogScale::new(350.0, 42_000.0);
+        assert_eq!((scale.min_exp, scale.max_exp), (2, 5));
+        assert_eq!(scale.ticks().count(), 4);
+
+        assert_eq!(scale.y(100.0), HEIGHT - MARGIN_BOTTOM);
+        assert_eq!(scale.y(100_000.0), MARGIN_TOP);
+        assert!(
+            (scale.y(1_000.0) - scale.y(10_000.0) - (scale.y(100.0) - scale.y(1_000.0))).abs()
+                < 1e-9
+        );
+
+        let single = LogScale::new(1_000.0, 1_000.0);
+        assert_eq!((single.min_exp, single.max_exp), (3, 4));
+    }
+
+    #[test]
+    fn renders_bar_per_part() {
+        let run = entry("abc", &[(1, 400, 1_200), (2, 52_000, 0)]);
+        let svg = bar_chart("2023", &run.days);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect x=").count(), 4 + 3);
+        assert!(svg.contains("<title>Day 2 Part 1: 52µs</title>"));
+
+        assert!(!bar_chart("empty", &[]).contains("<rect x="));
+    }
+
+    #[test]
+    fn renders_trend_of_runs() {
+        let history = [
+            entry("abc", &[(1, 1_000, 2_000)]),
+            entry("d<e", &[(1, 500, 500)]),
+        ];
+        let svg = trend_chart("2023", &history);
+
+        assert_eq!(svg.matches("<circle").count(), 2);
+        assert!(svg.contains("<title>d&lt;e: 1µs</title>"));
+        assert!(svg.contains("2 run(s), latest: 1µs"));
+        assert_eq!(escape("\"a&b\""), "&quot;a&amp;b&quot;");
+    }
+}
@@ -1,254 +1,358 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
+use crate::solutions;
 use crate::template::{
+    answers,
+    bench_history::{self, HistoryEntry},
+    charts, readme,
     readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    report::{OutputFormat, PartReport, PARSE_PART},
+    runner::{print_report, RunOptions, STACK_SIZE},
+    try_read_file, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{Day, DaySet, PuzzleId, Year};
+
+/// What a run of [`handle`] did, so that the caller decides how to exit.
+#[derive(Debug, Default)]
+pub struct Summary {
+    /// The number of parts that timed out or panicked.
+    pub failures: usize,
+    /// The benchmark history entry this run recorded, if any.
+    pub recorded: Option<HistoryEntry>,
+}
 
-pub fn handle(is_release: bool, is_timed: bool) {
+/// Runs the selected days of a year, `options` apply to every day.
+pub fn handle(
+    year: Year,
+    days: DaySet,
+    is_unsolved_only: bool,
+    is_release: bool,
+    jobs: usize,
+    options: RunOptions,
+) -> Summary {
+    let RunOptions {
+        format, is_timed, ..
+    } = options;
+    let is_text = format == OutputFormat::Text;
     let mut timings: Vec<Timings> = vec![];
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
-        }
+    let days: Vec<Day> = days
+        .iter()
+        .filter(|&day| !is_unsolved_only || !is_solved(PuzzleId::new(year, day)))
+        .collect();
+    // a partial run must not replace the benchmarks of the other days.
+    let is_complete = days.len() == DaySet::all().iter().count();
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    let Some(&first_day) = days.first() else {
+        eprintln!("No days selected.");
+        return Summary::default();
+    };
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let mut failures = 0;
+    let mut recorded = None;
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+    // timed runs stay serialized so that measurements do not compete for cores.
+    if is_timed || jobs <= 1 {
+        if is_timed && jobs > 1 {
+            eprintln!("Timed runs are serialized, ignoring `--jobs {jobs}`.");
         }
-    });
+
+        days.iter().for_each(|&day| {
+            print_header(day, day == first_day, is_text);
+
+            let puzzle = PuzzleId::new(year, day);
+            match run_day(puzzle, &options) {
+                Some(reports) => {
+                    failures += count_failures(&reports);
+                    timings.push(collect_timings(&reports, puzzle));
+                }
+                None if is_text => println!("Not solved."),
+                None => {}
+            }
+        });
+    } else {
+        let quiet = RunOptions {
+            is_quiet: true,
+            ..options
+        };
+
+        run_parallel(
+            &days,
+            jobs,
+            |&day| run_day(PuzzleId::new(year, day), &quiet),
+            |&day, reports| {
+                print_header(day, day == first_day, is_text);
+
+                match reports {
+                    Some(reports) => {
+                        for report in &reports {
+                            match format {
+                                OutputFormat::Text => print_report(report),
+                                OutputFormat::Json => println!("{}", report.to_line()),
+                            }
+                        }
+                        failures += count_failures(&reports);
+                        timings.push(collect_timings(&reports, PuzzleId::new(year, day)));
+                    }
+                    None if is_text => println!("Not solved."),
+                    None => {}
+                }
+            },
+        );
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if is_text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
+
+        if is_release && !is_complete {
+            eprintln!("Only some days ran, skipping benchmark history and README.");
+        } else if is_release {
+            let entry = HistoryEntry::new(&timings);
+            match bench_history::append(year, &entry) {
+                Ok(()) => recorded = Some(entry),
+                Err(e) => eprintln!("Failed to record benchmark history: {e}"),
+            }
+
+            let charts =
+                match bench_history::read(year).map(|history| charts::write(year, &history)) {
+                    Ok(Ok(charts)) => charts,
+                    Ok(Err(e)) => {
+                        eprintln!("Failed to write benchmark charts: {e}");
+                        vec![]
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to read benchmark history: {e}");
+                        vec![]
+                    }
+                };
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+            match readme_benchmarks::update(timings, total_millis, &charts) {
+                Ok(()) if is_text => println!("Successfully updated README with benchmarks."),
+                Ok(()) => {}
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
+
+            // the days table shows the timings of the run that was just recorded.
+            if let Err(e) = readme::update(year) {
+                eprintln!("Failed to update readme: {e}");
+            }
         }
     }
+
+    if failures > 0 {
+        eprintln!("{failures} part(s) timed out or panicked.");
+    }
+
+    Summary { failures, recorded }
 }
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
+fn count_failures(reports: &[PartReport]) -> usize {
+    reports.iter().filter(|r| r.error.is_some()).count()
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+fn print_header(day: Day, is_first: bool, is_text: bool) {
+    if is_text {
+        if !is_first {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Whether the answers of both parts of a puzzle are stored.
+fn is_solved(puzzle: PuzzleId) -> bool {
+    answers::read(puzzle).is_ok_and(|answers| answers.is_complete())
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+/// Runs a puzzle's solution in-process, returns [`None`] if the puzzle is not solved or has no input.
+fn run_day(puzzle: PuzzleId, options: &RunOptions) -> Option<Vec<PartReport>> {
+    let solution = solutions::get(puzzle)?;
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    match try_read_file("inputs", puzzle) {
+        // Hate windows
+        Ok(input) => Some((solution.run)(&input.replace('\r', ""), options)),
+        Err(e) => {
+            eprintln!("Could not read input of {puzzle}: {e}");
+            None
         }
+    }
+}
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
+/// Runs `work` for every item on a pool of `jobs` threads.
+/// `on_done` is called on the calling thread for every item in order, as soon as the item and all items before it finished.
+fn run_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut on_done: impl FnMut(&T, R),
+) {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let (tx, next, work) = (tx.clone(), &next, &work);
+
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    let _ = tx.send((i, work(item)));
+                })
+                .expect("failed to spawn worker thread");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+        // the receiver below finishes once every worker dropped its sender.
+        drop(tx);
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        let mut finished = BTreeMap::new();
+        let mut current = 0;
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        for (i, result) in rx {
+            finished.insert(i, result);
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+            while let Some(result) = finished.remove(&current) {
+                on_done(&items[current], result);
+                current += 1;
+            }
+        }
+    });
+}
 
-        let mut output = vec![];
+/// Collects the timings of the parse step and the successful parts of a day.
+fn collect_timings(reports: &[PartReport], puzzle: PuzzleId) -> Timings {
+    let mut timings = Timings {
+        puzzle,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+    };
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    for report in reports.iter().filter(|r| r.success) {
+        let stats = report.stats();
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        match report.part {
+            PARSE_PART => timings.parse = Some(stats),
+            1 => timings.part_1 = Some(stats),
+            2 => timings.part_2 = Some(stats),
+            _ => continue,
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = stats.median.as_nanos() as f64;
+        timings.total_nanos += nanos;
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+    timings
+}
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    use super::{collect_timings, run_parallel};
 
-                timings.total_nanos += nanos;
-            });
+    use crate::template::report::PartReport;
+    use crate::template::runner::BenchStats;
+    use crate::{day, year, PuzzleId};
 
-        timings
+    fn mock_report(part: u8, answer: Option<&str>, median: Duration) -> PartReport {
+        let stats = BenchStats {
+            median,
+            samples: 10,
+            ..BenchStats::default()
+        };
+        PartReport::new(day!(1), part, answer.map(String::from), &stats)
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    #[test]
+    fn test_well_formed() {
+        let res = collect_timings(
+            &[
+                mock_report(1, Some("0"), Duration::from_nanos(74)),
+                mock_report(2, Some("10"), Duration::from_micros(74130)),
+            ],
+            PuzzleId::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.total_nanos, 74130074_f64);
+        assert_eq!(res.part_1.unwrap().median, Duration::from_nanos(74));
+        assert_eq!(res.part_2.unwrap().median, Duration::from_micros(74130));
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn test_parse_step() {
+        let parse = PartReport::parse(day!(1), &BenchStats::single(Duration::from_micros(5)));
+        let res = collect_timings(
+            &[parse, mock_report(1, Some("0"), Duration::from_micros(10))],
+            PuzzleId::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.total_nanos, 15_000_f64);
+        assert_eq!(res.parse.unwrap().median, Duration::from_micros(5));
+        assert_eq!(res.part_2.is_none(), true);
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn test_missing_parts() {
+        let res = collect_timings(
+            &[
+                mock_report(1, None, Duration::from_millis(1)),
+                mock_report(2, None, Duration::from_millis(1)),
+            ],
+            PuzzleId::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
-
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn test_single_part() {
+        let res = collect_timings(
+            &[mock_report(2, Some("7"), Duration::from_millis(2))],
+            PuzzleId::new(year!(2023), day!(1)),
+        );
+        assert_eq!(res.total_nanos, 2_000_000_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.unwrap().samples, 10);
+    }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn test_parallel_order() {
+        let items: Vec<u64> = (0..8).collect();
+        let mut done = vec![];
+
+        // later items finish first, results must still arrive in order.
+        run_parallel(
+            &items,
+            4,
+            |&i| {
+                std::thread::sleep(Duration::from_millis(8 - i));
+                i * 2
+            },
+            |&i, result| done.push((i, result)),
+        );
+
+        assert_eq!(done, items.iter().map(|&i| (i, i * 2)).collect::<Vec<_>>());
     }
 }
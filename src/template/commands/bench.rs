@@ -0,0 +1,104 @@
+use std::{process, time::Duration};
+
+use crate::template::{
+    bench_history::{self, Change},
+    commands::all,
+    report::{OutputFormat, PARSE_PART},
+    runner::{RunOptions, DEFAULT_TIMEOUT},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::{DaySet, Year};
+
+/// Relative change of a median, in percent, that is reported if no `--threshold` is given.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Benchmarks all days of a year like `cargo time` does.
+/// If `compare` is set, the run recorded by this command is compared against the baseline run,
+/// and the command fails on regressions.
+pub fn handle(year: Year, compare: Option<Option<String>>, threshold: f64) {
+    let options = RunOptions {
+        format: OutputFormat::Text,
+        is_timed: true,
+        submit: None,
+        is_quiet: false,
+        timeout: Some(DEFAULT_TIMEOUT),
+    };
+    let summary = all::handle(year, DaySet::all(), false, true, 1, options);
+
+    let Some(baseline) = compare else {
+        if summary.failures > 0 {
+            process::exit(1);
+        }
+        return;
+    };
+
+    let Some(current) = summary.recorded else {
+        eprintln!("This run recorded no benchmark history, nothing to compare.");
+        process::exit(1);
+    };
+
+    let history = match bench_history::read(year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    // only runs before the one just recorded are candidates for the baseline.
+    let earlier = history
+        .iter()
+        .rposition(|entry| *entry == current)
+        .map_or(&history[..0], |i| &history[..=i]);
+
+    let Some(baseline_entry) = bench_history::find_baseline(earlier, baseline.as_deref()) else {
+        eprintln!(
+            "No baseline run found for \"{}\".",
+            baseline.as_deref().unwrap_or("previous run")
+        );
+        process::exit(1);
+    };
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Comparing{ANSI_RESET} {} against {} (threshold: {threshold}%)",
+        current.label(),
+        baseline_entry.label()
+    );
+
+    let changes = bench_history::compare(baseline_entry, &current, threshold);
+
+    if changes.is_empty() {
+        println!("No day changed beyond the threshold.");
+    }
+
+    for change in &changes {
+        println!("{}", format_change(change));
+    }
+
+    if summary.failures > 0 || changes.iter().any(Change::is_regression) {
+        process::exit(1);
+    }
+}
+
+fn format_change(change: &Change) -> String {
+    let verdict = if change.is_regression() {
+        "✖ regressed"
+    } else {
+        "✔ improved"
+    };
+
+    let part = if change.part == PARSE_PART {
+        "Parse".into()
+    } else {
+        format!("Part {}", change.part)
+    };
+
+    format!(
+        "Day {:02} {part}: {:.1?} → {:.1?} ({:+.1}%) {verdict}",
+        change.day,
+        Duration::from_nanos(change.baseline_ns),
+        Duration::from_nanos(change.current_ns),
+        change.percent
+    )
+}
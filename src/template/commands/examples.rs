@@ -0,0 +1,57 @@
+use std::{fs, process};
+
+use crate::template::{aoc_client, examples, puzzle_args, ANSI_BOLD, ANSI_RESET};
+use crate::PuzzleId;
+
+pub fn handle(puzzle_id: PuzzleId, write: bool) {
+    let puzzle_path = aoc_client::get_puzzle_path(puzzle_id);
+
+    let puzzle = match fs::read_to_string(&puzzle_path) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("Could not read puzzle \"{puzzle_path}\": {e}");
+            eprintln!(
+                "Type `cargo download {}` to download it.",
+                puzzle_args(puzzle_id)
+            );
+            process::exit(1);
+        }
+    };
+
+    let proposed = examples::from_puzzle(&puzzle);
+
+    if proposed.is_empty() {
+        println!("No examples found in \"{puzzle_path}\".");
+        return;
+    }
+
+    for example in &proposed {
+        println!("{ANSI_BOLD}{}{ANSI_RESET}", example.name);
+        for part in [1, 2] {
+            let answer = example.expected.get(part).unwrap_or("-");
+            println!("Part {part}: {answer}");
+        }
+        println!("{}", example.input.trim_end());
+        println!();
+    }
+
+    if !write {
+        println!(
+            "Type `cargo examples {} --write` to add them to \"{}\".",
+            puzzle_args(puzzle_id),
+            examples::get_dir(puzzle_id)
+        );
+        return;
+    }
+
+    match examples::write(puzzle_id, &proposed) {
+        Ok(()) => println!(
+            "🎄 Added examples to \"{}\".",
+            examples::get_manifest_path(puzzle_id)
+        ),
+        Err(e) => {
+            eprintln!("Failed to write examples: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use std::process;
+
+use crate::template::readme::{self, Section};
+use crate::Year;
+
+/// Regenerates the sections of the readme from local data.
+pub fn handle(year: Year) {
+    match readme::update(year) {
+        Ok(0) => {
+            let markers: Vec<&str> = Section::ALL.iter().map(|s| s.marker()).collect();
+            println!(
+                "No sections to update, add any of {} to README.md.",
+                markers.join(", ")
+            );
+        }
+        Ok(n) => println!("🎄 Updated {n} section(s) of README.md."),
+        Err(e) => {
+            eprintln!("Failed to update readme: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,18 +1,29 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::{aoc_client, examples, puzzle_args};
+use crate::PuzzleId;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// Answer type of both parts if no `--type` is given.
+pub const DEFAULT_ANSWER_TYPE: &str = "u32";
 
-pub fn part_one(input: &str) -> Option<u32> {
+/// A template in this file replaces [`MODULE_TEMPLATE`], see [`render`] for its placeholders.
+static TEMPLATE_PATH: &str = "data/templates/module.rs";
+/// A template in this file replaces [`PARSE_MODULE_TEMPLATE`] for `--parse`.
+static PARSE_TEMPLATE_PATH: &str = "data/templates/module_parse.rs";
+
+const MODULE_TEMPLATE: &str = r#"// PUZZLE_TITLE
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
+
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
@@ -22,44 +33,194 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        advent_of_code::template::examples::assert_part(PUZZLE, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        advent_of_code::template::examples::assert_part(PUZZLE, 2, part_two);
     }
 }
 "#;
 
+const PARSE_MODULE_TEMPLATE: &str = r#"// PUZZLE_TITLE
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER, parse);
+
+pub fn parse(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+pub fn part_one(input: &[String]) -> Option<ANSWER_TYPE> {
+    None
+}
+
+pub fn part_two(input: &[String]) -> Option<ANSWER_TYPE> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::template::examples::assert_part(PUZZLE, 1, |input| part_one(&parse(input)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::template::examples::assert_part(PUZZLE, 2, |input| part_two(&parse(input)));
+    }
+}
+"#;
+
+/// Fills in the placeholders of a module template:
+/// - `YEAR_NUMBER` and `DAY_NUMBER`, e.g. `2023` and `5`.
+/// - `ANSWER_TYPE`, the return type of both parts.
+/// - `PUZZLE_TITLE`, e.g. `Day 5: If You Give A Seed A Fertilizer`, or `Day 5` if the puzzle is not downloaded.
+fn render(template: &str, puzzle: PuzzleId, answer_type: &str, title: Option<&str>) -> String {
+    let day = puzzle.day.into_inner();
+    let title = title.map_or_else(|| format!("Day {day}"), |t| format!("Day {day}: {t}"));
+
+    template
+        .replace("YEAR_NUMBER", &puzzle.year.to_string())
+        .replace("DAY_NUMBER", &day.to_string())
+        .replace("ANSWER_TYPE", answer_type)
+        .replace("PUZZLE_TITLE", &title)
+}
+
+/// Reads the user's module template, falls back to the built-in one if there is none.
+fn read_template(is_parse: bool) -> io::Result<String> {
+    let (path, default) = if is_parse {
+        (PARSE_TEMPLATE_PATH, PARSE_MODULE_TEMPLATE)
+    } else {
+        (TEMPLATE_PATH, MODULE_TEMPLATE)
+    };
+
+    match fs::read_to_string(path) {
+        Ok(template) => {
+            println!("Using module template \"{path}\"");
+            Ok(template)
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(default.into()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Moves an existing file out of the way, to `PATH.bak` or the first free `PATH.bakN`.
+/// The suffix keeps cargo from picking up the backup of a module as a binary.
+fn backup_file(path: &str) -> io::Result<String> {
+    let backup = (1..)
+        .map(|n| match n {
+            1 => format!("{path}.bak"),
+            n => format!("{path}.bak{n}"),
+        })
+        .find(|backup| !Path::new(backup).exists())
+        .unwrap_or_default();
+
+    fs::rename(path, &backup)?;
+    Ok(backup)
+}
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+static REGISTRY_PATH: &str = "src/solutions.rs";
+
+fn registry_entry(puzzle: PuzzleId) -> String {
+    format!(
+        "    y{}_day{} => \"bin/{}.rs\",",
+        puzzle.year,
+        puzzle.day,
+        puzzle.bin_name()
+    )
+}
+
+/// The entry of a `register!` line, without the attributes that gate it behind a feature.
+fn entry_key(line: &str) -> &str {
+    let line = line.trim();
+    match line.strip_prefix("#[") {
+        Some(rest) => rest.split_once(']').map_or(line, |(_, entry)| entry.trim()),
+        None => line,
+    }
+}
+
+/// Inserts a puzzle into the `register!` list of the solution registry, keeping the list ordered by year and day.
+/// Returns [`None`] if the puzzle is already registered or the list could not be found.
+fn register_solution(registry: &str, puzzle: PuzzleId) -> Option<String> {
+    let entry = registry_entry(puzzle);
+
+    if registry.lines().any(|l| entry_key(l) == entry.trim()) {
+        return None;
+    }
+
+    let mut lines: Vec<&str> = registry.lines().collect();
+    let start = lines.iter().position(|l| l.starts_with("register! {"))? + 1;
+    let end = start + lines[start..].iter().position(|l| *l == "}")?;
+
+    let pos = lines[start..end]
+        .iter()
+        .position(|l| entry_key(l) > entry.trim())
+        .map_or(end, |i| start + i);
+
+    lines.insert(pos, &entry);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Creates the module, input and examples of a puzzle.
+/// With `is_force`, an existing module is backed up and replaced, the input and examples are always kept.
+pub fn handle(puzzle: PuzzleId, answer_type: &str, is_parse: bool, is_force: bool) {
+    let input_path = aoc_client::get_input_path(puzzle);
+    let example_dir = examples::get_dir(puzzle);
+    let example_path = format!("{example_dir}/example.txt");
+    let manifest_path = examples::get_manifest_path(puzzle);
+    let module_path = format!("src/bin/{}.rs", puzzle.bin_name());
+
+    let puzzle_path = aoc_client::get_puzzle_path(puzzle);
+    let description = fs::read_to_string(&puzzle_path).ok();
+
+    let template = match read_template(is_parse) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read module template: {e}");
+            process::exit(1);
+        }
+    };
+
+    if is_force && Path::new(&module_path).exists() {
+        match backup_file(&module_path) {
+            Ok(backup) => println!("Moved existing module file to \"{backup}\""),
+            Err(e) => {
+                eprintln!("Failed to back up module file: {e}");
+                process::exit(1);
+            }
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            eprintln!("Module file \"{module_path}\" exists, pass `--force` to replace it.");
+            process::exit(1);
+        }
         Err(e) => {
             eprintln!("Failed to create module file: {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let title = description.as_deref().and_then(aoc_client::puzzle_title);
+
+    match file.write_all(render(&template, puzzle, answer_type, title.as_deref()).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -69,26 +230,193 @@ pub fn handle(day: Day) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    let registry = fs::read_to_string(REGISTRY_PATH).unwrap_or_default();
+    let is_registered = registry
+        .lines()
+        .any(|l| entry_key(l) == registry_entry(puzzle).trim());
+
+    match register_solution(&registry, puzzle).map(|updated| fs::write(REGISTRY_PATH, updated)) {
+        Some(Ok(())) => {
+            println!("Registered solution in \"{REGISTRY_PATH}\"");
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+        Some(Err(e)) => {
+            eprintln!("Failed to register solution: {e}");
             process::exit(1);
         }
+        None if is_registered => {}
+        None => {
+            eprintln!("Could not register solution, add it to \"{REGISTRY_PATH}\" by hand.");
+        }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    if let Some(dir) = Path::new(&input_path).parent() {
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("Failed to create input directory: {e}");
+            process::exit(1);
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+    }
+
+    if Path::new(&input_path).exists() {
+        println!("Kept existing input file \"{}\"", &input_path);
+    } else {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    // a downloaded puzzle lets the tests start out with its examples and answers.
+    let proposed = description
+        .as_deref()
+        .map(examples::from_puzzle)
+        .unwrap_or_default();
+    let (kept, proposed): (Vec<_>, Vec<_>) = proposed
+        .into_iter()
+        .partition(|example| Path::new(&format!("{example_dir}/{}.txt", example.name)).exists());
+
+    if !kept.is_empty() {
+        println!(
+            "Kept {} existing example(s) in \"{}\"",
+            kept.len(),
+            &example_dir
+        );
+    }
+
+    if proposed.is_empty() && kept.is_empty() {
+        if let Err(e) = fs::create_dir_all(&example_dir) {
+            eprintln!("Failed to create example directory: {e}");
             process::exit(1);
         }
+
+        if Path::new(&example_path).exists() {
+            println!("Kept existing example file \"{}\"", &example_path);
+        } else {
+            match create_file(&example_path) {
+                Ok(_) => {
+                    println!("Created empty example file \"{}\"", &example_path);
+                }
+                Err(e) => {
+                    eprintln!("Failed to create example file: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+
+        // an existing manifest may already list the answers of several examples.
+        match safe_create_file(&manifest_path)
+            .and_then(|mut file| file.write_all(examples::MANIFEST_TEMPLATE.as_bytes()))
+        {
+            Ok(()) => {
+                println!("Created example manifest \"{}\"", &manifest_path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => {
+                eprintln!("Failed to create example manifest: {e}");
+                process::exit(1);
+            }
+        }
+    } else if !proposed.is_empty() {
+        match examples::write(puzzle, &proposed) {
+            Ok(()) => {
+                println!(
+                    "Created {} example(s) from the puzzle in \"{}\"",
+                    proposed.len(),
+                    &example_dir
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to write examples: {e}");
+                process::exit(1);
+            }
+        }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!(
+        "🎄 Type `cargo solve {}` to run your solution.",
+        puzzle_args(puzzle)
+    );
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{register_solution, render, MODULE_TEMPLATE, PARSE_MODULE_TEMPLATE};
+    use crate::{day, year, PuzzleId};
+
+    const REGISTRY: &str = "use foo;\n\nregister! {\n    y2023_day01 => \"bin/2023_01.rs\",\n    y2023_day03 => \"bin/2023_03.rs\",\n}\n";
+
+    #[test]
+    fn registers_day_in_order() {
+        let updated = register_solution(REGISTRY, PuzzleId::new(year!(2023), day!(2))).unwrap();
+        assert_eq!(
+            updated,
+            "use foo;\n\nregister! {\n    y2023_day01 => \"bin/2023_01.rs\",\n    y2023_day02 => \"bin/2023_02.rs\",\n    y2023_day03 => \"bin/2023_03.rs\",\n}\n"
+        );
+    }
+
+    #[test]
+    fn registers_last_day() {
+        let updated = register_solution(REGISTRY, PuzzleId::new(year!(2023), day!(25))).unwrap();
+        assert!(updated.ends_with("    y2023_day25 => \"bin/2023_25.rs\",\n}\n"));
+    }
+
+    #[test]
+    fn registers_by_year() {
+        let earlier = register_solution(REGISTRY, PuzzleId::new(year!(2022), day!(25))).unwrap();
+        assert!(earlier
+            .contains("register! {\n    y2022_day25 => \"bin/2022_25.rs\",\n    y2023_day01"));
+
+        let later = register_solution(REGISTRY, PuzzleId::new(year!(2024), day!(1))).unwrap();
+        assert!(later.ends_with("    y2024_day01 => \"bin/2024_01.rs\",\n}\n"));
+    }
+
+    #[test]
+    fn skips_registered_day() {
+        let puzzle = PuzzleId::new(year!(2023), day!(3));
+        assert_eq!(register_solution(REGISTRY, puzzle), None);
+        assert_eq!(register_solution("mod foo;", puzzle), None);
+    }
+
+    #[test]
+    fn skips_gated_entries() {
+        let registry = "register! {\n    y2023_day23 => \"bin/2023_23.rs\",\n    #[cfg(feature = \"z3\")] y2023_day24 => \"bin/2023_24.rs\",\n}\n";
+
+        let updated = register_solution(registry, PuzzleId::new(year!(2023), day!(25))).unwrap();
+        assert!(updated.ends_with(
+            "y2023_day24 => \"bin/2023_24.rs\",\n    y2023_day25 => \"bin/2023_25.rs\",\n}\n"
+        ));
+        assert_eq!(
+            register_solution(registry, PuzzleId::new(year!(2023), day!(24))),
+            None
+        );
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let puzzle = PuzzleId::new(year!(2023), day!(5));
+        let module = render(
+            MODULE_TEMPLATE,
+            puzzle,
+            "u64",
+            Some("If You Give A Seed A Fertilizer"),
+        );
+
+        assert!(module.starts_with(
+            "// Day 5: If You Give A Seed A Fertilizer\nadvent_of_code::solution!(2023, 5);\n"
+        ));
+        assert!(module.contains("pub fn part_two(input: &str) -> Option<u64> {"));
+
+        let module = render(PARSE_MODULE_TEMPLATE, puzzle, "i64", None);
+        assert!(module.starts_with("// Day 5\nadvent_of_code::solution!(2023, 5, parse);\n"));
+        assert!(module.contains("pub fn part_one(input: &[String]) -> Option<i64> {"));
+
+        for placeholder in ["YEAR_NUMBER", "DAY_NUMBER", "ANSWER_TYPE", "PUZZLE_TITLE"] {
+            assert!(!module.contains(placeholder));
+        }
+    }
 }
@@ -1,25 +1,64 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::Day;
+use crate::template::{report::OutputFormat, runner::RunOptions, InputSource};
+use crate::PuzzleId;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Runs a puzzle's binary, `is_alloc` builds it with the counting allocator of [`crate::template::alloc`].
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    is_alloc: bool,
+    options: RunOptions,
+    input: &InputSource,
+) {
+    let RunOptions {
+        format,
+        is_timed,
+        submit,
+        timeout,
+        ..
+    } = options;
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    if is_alloc {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("alloc_stats".to_string());
+    }
+
+    // days gated behind a feature only build if the feature is passed on.
+    if cfg!(feature = "z3") {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("z3".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    if time {
+    if is_timed {
         cmd_args.push("--time".to_string());
     }
 
+    if format != OutputFormat::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs().to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+
+    // stdin is inherited, so `--input -` reads whatever is piped into `cargo solve`.
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -27,5 +66,10 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    let status = cmd.wait().unwrap();
+
+    // a part that timed out or panicked makes the binary exit with an error.
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
@@ -0,0 +1,199 @@
+use std::process;
+use std::time::Duration;
+
+use crate::solutions::{self, Solution};
+use crate::template::report::{PartReport, PARSE_PART};
+use crate::template::runner::RunOptions;
+use crate::template::{answers, try_read_file, ANSI_BOLD, ANSI_RESET};
+use crate::Year;
+
+/// The outcome of comparing a part's answer against the stored answer.
+#[derive(Debug, PartialEq, Eq)]
+enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    Missing,
+}
+
+fn check(expected: Option<&str>, actual: Option<&str>) -> Verdict {
+    match (expected, actual) {
+        (None, _) => Verdict::Missing,
+        (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+        (Some(expected), actual) => Verdict::Fail {
+            expected: expected.into(),
+            actual: actual.map(String::from),
+        },
+    }
+}
+
+/// The answer of a part, or why the part did not finish.
+type Outcome = Result<Option<String>, String>;
+
+/// Checks every solution of a year against its stored answers.
+/// Each part runs guarded like in `all`, a part that times out or panics fails without stopping the others.
+pub fn handle(year: Year, timeout: Option<Duration>) {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for solution in solutions::of_year(year) {
+        let day = solution.puzzle.day;
+
+        let expected = match answers::read(solution.puzzle) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Day {day}: {e}");
+                process::exit(1);
+            }
+        };
+
+        // days without stored answers are not worth running.
+        let actual = if expected.is_empty() {
+            [Ok(None), Ok(None)]
+        } else {
+            solve(solution, timeout)
+        };
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+        for (part, actual) in [1, 2].into_iter().zip(actual) {
+            let actual = match actual {
+                Ok(actual) => actual,
+                Err(error) => {
+                    failed += 1;
+                    println!("Part {part}: ✖ {error}");
+                    continue;
+                }
+            };
+
+            match check(expected.get(part), actual.as_deref()) {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("Part {part}: ✔ pass");
+                }
+                Verdict::Fail { expected, actual } => {
+                    failed += 1;
+                    let actual = actual.unwrap_or_else(|| "no answer".into());
+                    println!("Part {part}: ✖ fail (expected {expected}, got {actual})");
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    println!("Part {part}: ? missing");
+                }
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Verified:{ANSI_RESET} {passed} passed, {failed} failed, {missing} missing"
+    );
+
+    if missing > 0 {
+        println!("Missing answers are recorded by a correct `--submit`, or can be added to \"data/{year}/answers/DD.toml\" by hand.");
+    }
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Solves both parts of a day's input, a missing input yields no answers.
+fn solve(solution: &Solution, timeout: Option<Duration>) -> [Outcome; 2] {
+    let options = RunOptions {
+        is_quiet: true,
+        timeout,
+        ..RunOptions::default()
+    };
+
+    match try_read_file("inputs", solution.puzzle) {
+        // Hate windows
+        Ok(input) => {
+            let reports = (solution.run)(&input.replace('\r', ""), &options);
+            [1, 2].map(|part| outcome(&reports, part))
+        }
+        Err(e) => {
+            eprintln!("Could not read input of {}: {e}", solution.puzzle);
+            [Ok(None), Ok(None)]
+        }
+    }
+}
+
+/// Picks the outcome of a part from the reports of a day, a failed parse step fails both parts.
+fn outcome(reports: &[PartReport], part: u8) -> Outcome {
+    let report = reports
+        .iter()
+        .find(|report| report.part == part)
+        .or_else(|| reports.iter().find(|report| report.part == PARSE_PART));
+
+    match report {
+        Some(PartReport {
+            error: Some(error), ..
+        }) => Err(error.clone()),
+        Some(report) if report.part == part => Ok(report.answer.clone()),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, outcome, Verdict};
+    use crate::day;
+    use crate::template::report::{PartReport, PARSE_PART};
+    use crate::template::runner::{BenchStats, Failure};
+
+    #[test]
+    fn passes_matching_answer() {
+        assert_eq!(check(Some("35"), Some("35")), Verdict::Pass);
+    }
+
+    #[test]
+    fn fails_changed_or_absent_answer() {
+        assert_eq!(
+            check(Some("35"), Some("36")),
+            Verdict::Fail {
+                expected: "35".into(),
+                actual: Some("36".into())
+            }
+        );
+        assert_eq!(
+            check(Some("35"), None),
+            Verdict::Fail {
+                expected: "35".into(),
+                actual: None
+            }
+        );
+    }
+
+    #[test]
+    fn missing_without_stored_answer() {
+        assert_eq!(check(None, Some("35")), Verdict::Missing);
+        assert_eq!(check(None, None), Verdict::Missing);
+    }
+
+    #[test]
+    fn failed_parts_fail_on_their_own() {
+        let stats = BenchStats::default();
+        let reports = [
+            PartReport::new(day!(5), 1, Some("35".into()), &stats),
+            PartReport::failure(day!(5), 2, Failure::Timeout),
+        ];
+
+        assert_eq!(outcome(&reports, 1), Ok(Some("35".into())));
+        assert_eq!(outcome(&reports, 2), Err("timeout".into()));
+    }
+
+    #[test]
+    fn failed_parse_fails_both_parts() {
+        let reports = [PartReport::failure(
+            day!(5),
+            PARSE_PART,
+            Failure::Panic("bad input".into()),
+        )];
+
+        assert_eq!(outcome(&reports, 1), Err("panicked: bad input".into()));
+        assert_eq!(outcome(&reports, 2), Err("panicked: bad input".into()));
+        assert_eq!(outcome(&[], 1), Ok(None));
+    }
+}
@@ -0,0 +1,283 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    examples, puzzle_args, readme_benchmarks::get_path_for_bin, report::PartReport,
+    runner::print_report, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::PuzzleId;
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification time of every watched file, [`None`] if the file does not exist.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Answers of a run keyed by part, [`None`] if the part returned no result.
+type RunAnswers = BTreeMap<u8, Option<String>>;
+
+/// Reruns a day whenever its source, `src/lib.rs`, its input or one of its examples changes.
+/// Every run rebuilds the day, runs its example tests and then its input, and shows how the answers changed.
+pub fn handle(puzzle: PuzzleId, release: bool) {
+    if !Path::new(&get_path_for_bin(puzzle)).exists() {
+        eprintln!("Puzzle {puzzle} has no solution to watch.");
+        eprintln!(
+            "Type `cargo scaffold {}` to create it.",
+            puzzle_args(puzzle)
+        );
+        process::exit(1);
+    }
+
+    let mut files = snapshot(&watched_paths(puzzle));
+    let mut previous: Option<RunAnswers> = None;
+
+    loop {
+        if let Some(answers) = run_cycle(puzzle, release) {
+            if let Some(previous) = &previous {
+                println!();
+                println!("{ANSI_BOLD}Changes{ANSI_RESET}");
+                for line in diff_answers(previous, &answers) {
+                    println!("{line}");
+                }
+            }
+            previous = Some(answers);
+        }
+
+        println!();
+        println!(
+            "{ANSI_ITALIC}Watching {} file(s) of {puzzle}, press Ctrl-C to stop.{ANSI_RESET}",
+            files.values().filter(|modified| modified.is_some()).count()
+        );
+
+        let changed = wait_for_change(puzzle, &mut files);
+        let changed: Vec<String> = changed.iter().map(|p| p.display().to_string()).collect();
+        println!();
+        println!("{ANSI_BOLD}Changed:{ANSI_RESET} {}", changed.join(", "));
+    }
+}
+
+/// Files that affect a day's answers. Named examples are listed again on every poll, so new ones are picked up.
+fn watched_paths(puzzle: PuzzleId) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = vec![
+        get_path_for_bin(puzzle).into(),
+        "./src/lib.rs".into(),
+        puzzle.data_path("inputs", "txt").into(),
+        puzzle.data_path("examples", "txt").into(),
+        examples::get_manifest_path(puzzle).into(),
+    ];
+
+    if let Ok(entries) = fs::read_dir(examples::get_dir(puzzle)) {
+        paths.extend(
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt")),
+        );
+    }
+
+    paths
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// Paths that were created, removed or modified between two snapshots.
+fn changed_paths(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let paths: BTreeSet<&PathBuf> = before.keys().chain(after.keys()).collect();
+
+    paths
+        .into_iter()
+        .filter(|&path| before.get(path).copied().flatten() != after.get(path).copied().flatten())
+        .cloned()
+        .collect()
+}
+
+/// Polls the watched files until one of them changed, returns the changed paths.
+/// A change is only reported once the files stayed untouched for one poll, as editors often save in several steps.
+fn wait_for_change(puzzle: PuzzleId, files: &mut Snapshot) -> Vec<PathBuf> {
+    let mut current = files.clone();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let next = snapshot(&watched_paths(puzzle));
+        let is_settled = next == current;
+        current = next;
+
+        if is_settled {
+            let changed = changed_paths(files, &current);
+            if !changed.is_empty() {
+                files.clone_from(&current);
+                return changed;
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn cargo(args: &[&str], release: bool) -> Command {
+    let mut cmd = Command::new("cargo");
+    cmd.args(args);
+    if release {
+        cmd.arg("--release");
+    }
+    cmd
+}
+
+fn wait(cmd: &mut Command) -> Option<ExitStatus> {
+    match cmd.status() {
+        Ok(status) => Some(status),
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            None
+        }
+    }
+}
+
+/// Rebuilds the day, runs its example tests and then its input.
+/// Returns the answers of the input, [`None`] if the day could not be built or run.
+fn run_cycle(puzzle: PuzzleId, release: bool) -> Option<RunAnswers> {
+    let bin = puzzle.bin_name();
+
+    println!("{ANSI_BOLD}Build{ANSI_RESET}");
+    if !wait(&mut cargo(&["build", "--quiet", "--bin", &bin], release))?.success() {
+        println!("✖ build failed");
+        return None;
+    }
+    println!("✔ built {bin}");
+
+    println!();
+    println!("{ANSI_BOLD}Examples{ANSI_RESET}");
+    // failing examples still run the input, its answers may help to find the bug.
+    if !wait(&mut cargo(&["test", "--quiet", "--bin", &bin], release))?.success() {
+        println!("✖ example tests failed");
+    }
+
+    println!();
+    println!("{ANSI_BOLD}Input{ANSI_RESET}");
+    let output = match cargo(&["run", "--quiet", "--bin", &bin], release)
+        .args(["--", "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            return None;
+        }
+    };
+
+    let mut answers = RunAnswers::new();
+
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        match PartReport::from_line(line) {
+            Some(report) => {
+                print_report(&report);
+                if !report.is_parse() {
+                    answers.insert(report.part, report.answer);
+                }
+            }
+            // anything the solution prints itself.
+            None => println!("{line}"),
+        }
+    }
+
+    Some(answers)
+}
+
+/// Describes for every part how its answer changed since the previous run.
+fn diff_answers(previous: &RunAnswers, current: &RunAnswers) -> Vec<String> {
+    let parts: BTreeSet<u8> = previous.keys().chain(current.keys()).copied().collect();
+    let show = |answer: Option<&String>| answer.map_or("no answer", String::as_str).to_string();
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let before = previous.get(&part).map(Option::as_ref);
+            let after = current.get(&part).and_then(Option::as_ref);
+
+            match before {
+                None => format!("Part {part}: new {}", show(after)),
+                Some(before) if before == after => format!("Part {part}: unchanged"),
+                Some(before) => format!("Part {part}: {} → {}", show(before), show(after)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{changed_paths, diff_answers, RunAnswers, Snapshot};
+
+    fn answers(entries: &[(u8, Option<&str>)]) -> RunAnswers {
+        entries
+            .iter()
+            .map(|&(part, answer)| (part, answer.map(String::from)))
+            .collect()
+    }
+
+    #[test]
+    fn detects_changed_paths() {
+        let time = |secs| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        let before: Snapshot = [
+            ("src/bin/2023_05.rs".into(), time(1)),
+            ("src/lib.rs".into(), time(1)),
+            ("data/2023/inputs/05.txt".into(), None),
+            ("data/2023/examples/05/large.txt".into(), time(1)),
+        ]
+        .into();
+        let after: Snapshot = [
+            ("src/bin/2023_05.rs".into(), time(2)),
+            ("src/lib.rs".into(), time(1)),
+            ("data/2023/inputs/05.txt".into(), time(2)),
+            ("data/2023/examples/05/small.txt".into(), time(2)),
+        ]
+        .into();
+
+        let expected: Vec<PathBuf> = vec![
+            "data/2023/examples/05/large.txt".into(),
+            "data/2023/examples/05/small.txt".into(),
+            "data/2023/inputs/05.txt".into(),
+            "src/bin/2023_05.rs".into(),
+        ];
+        assert_eq!(changed_paths(&before, &after), expected);
+        assert!(changed_paths(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let previous = answers(&[(1, Some("35")), (2, Some("46"))]);
+
+        assert_eq!(
+            diff_answers(&previous, &answers(&[(1, Some("35")), (2, None)])),
+            ["Part 1: unchanged", "Part 2: 46 → no answer"]
+        );
+        // a failed parse step runs no parts.
+        assert_eq!(
+            diff_answers(&answers(&[(1, Some("35"))]), &answers(&[])),
+            ["Part 1: 35 → no answer"]
+        );
+        assert_eq!(
+            diff_answers(&answers(&[]), &previous),
+            ["Part 1: new 35", "Part 2: new 46"]
+        );
+    }
+}
@@ -0,0 +1,389 @@
+/// Module that reads the named examples of a day from `data/YYYY/examples/DD/`.
+/// Each example is a `NAME.txt` file, its expected answers are listed in the manifest `answers.toml` under `[NAME]`.
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use crate::template::{answers::Answers, aoc_client::decode_entities};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse examples manifest: {e}"),
+            Error::IO(e) => write!(f, "could not read example: {e}"),
+        }
+    }
+}
+
+/// Content of a day's example manifest.
+pub const MANIFEST_TEMPLATE: &str = r#"# Expected answers of the examples in this directory, keyed by file name without `.txt`.
+[example]
+# part_1 = ""
+# part_2 = ""
+"#;
+
+/// A named example input of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Answers,
+}
+
+#[must_use]
+pub fn get_dir(puzzle: PuzzleId) -> String {
+    format!("data/{}/examples/{}", puzzle.year, puzzle.day)
+}
+
+#[must_use]
+pub fn get_manifest_path(puzzle: PuzzleId) -> String {
+    format!("{}/answers.toml", get_dir(puzzle))
+}
+
+fn parse_manifest(s: &str) -> Result<BTreeMap<String, Answers>, Error> {
+    toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+}
+
+fn serialize_manifest(manifest: &BTreeMap<String, Answers>) -> Result<String, Error> {
+    let tables = toml::to_string(manifest).map_err(|e| Error::Parser(e.to_string()))?;
+    // keeps the explanation at the top of the manifest.
+    let header = MANIFEST_TEMPLATE.lines().next().unwrap_or_default();
+    Ok(format!("{header}\n{tables}"))
+}
+
+/// Reads every example listed in the manifest of a day, ordered by name.
+/// A day without a manifest has no named examples.
+pub fn read(puzzle: PuzzleId) -> Result<Vec<Example>, Error> {
+    let manifest_path = get_manifest_path(puzzle);
+
+    if !Path::new(&manifest_path).exists() {
+        return Ok(vec![]);
+    }
+
+    parse_manifest(&fs::read_to_string(manifest_path)?)?
+        .into_iter()
+        .map(|(name, expected)| {
+            let path = format!("{}/{name}.txt", get_dir(puzzle));
+            let input = fs::read_to_string(&path)
+                .map_err(|e| io::Error::new(e.kind(), format!("\"{path}\": {e}")))?;
+
+            Ok(Example {
+                // Hate windows
+                input: input.replace('\r', ""),
+                name,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// Writes examples to the directory of a day and adds their answers to the manifest.
+/// Existing examples of the same name are replaced, all others are kept.
+pub fn write(puzzle: PuzzleId, examples: &[Example]) -> Result<(), Error> {
+    let dir = get_dir(puzzle);
+    let manifest_path = get_manifest_path(puzzle);
+    fs::create_dir_all(&dir)?;
+
+    let mut manifest = if Path::new(&manifest_path).exists() {
+        parse_manifest(&fs::read_to_string(&manifest_path)?)?
+    } else {
+        BTreeMap::new()
+    };
+
+    for example in examples {
+        fs::write(format!("{dir}/{}.txt", example.name), &example.input)?;
+        manifest.insert(example.name.clone(), example.expected.clone());
+    }
+
+    fs::write(manifest_path, serialize_manifest(&manifest)?)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Proposes examples from the HTML of a puzzle description, see [`crate::template::aoc_client::download`].
+///
+/// Each part starts with a `<h2>` heading. The first `<pre><code>` block of a part is taken as its example,
+/// the last highlighted `<code><em>` value of the part as the expected answer.
+/// A part without an example of its own shares the example of the previous part.
+#[must_use]
+pub fn from_puzzle(puzzle: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+
+    let parts = puzzle.split("<h2").skip(1).take(2);
+
+    for (part, section) in (1..).zip(parts) {
+        let answer = last_between(section, "<code><em>", "</em></code>").map(decode_code);
+        let input = first_between(section, "<pre><code>", "</code></pre>").map(decode_code);
+
+        match (input, examples.last_mut()) {
+            // the part shares the example of the previous part.
+            (Some(input), Some(previous)) if previous.input == input => {
+                set_answer(previous, part, answer);
+            }
+            (None, Some(previous)) => set_answer(previous, part, answer),
+            (Some(input), _) => {
+                let name = match examples.len() {
+                    0 => "example".to_string(),
+                    n => format!("example_{}", n + 1),
+                };
+                let mut example = Example {
+                    name,
+                    input,
+                    expected: Answers::default(),
+                };
+                set_answer(&mut example, part, answer);
+                examples.push(example);
+            }
+            (None, None) => {}
+        }
+    }
+
+    examples
+}
+
+fn set_answer(example: &mut Example, part: u8, answer: Option<String>) {
+    if let Some(answer) = answer {
+        example.expected.set(part, &answer);
+    }
+}
+
+fn first_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.find(start)? + start.len();
+    let len = s[from..].find(end)?;
+    Some(&s[from..from + len])
+}
+
+fn last_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.rfind(start)? + start.len();
+    let len = s[from..].find(end)?;
+    Some(&s[from..from + len])
+}
+
+/// Drops the tags within a code block and decodes its entities.
+fn decode_code(html: &str) -> String {
+    let mut code = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        code.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    code.push_str(rest);
+
+    decode_entities(&code)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A part whose answer on an example differs from the manifest.
+#[derive(Debug, PartialEq, Eq)]
+struct Mismatch {
+    example: String,
+    expected: String,
+    actual: Option<String>,
+}
+
+/// Runs a part on every example that has an expected answer for it and lists the wrong answers.
+/// Examples without an answer for the part are skipped, e.g. because they only apply to the other part.
+fn check<T: Display>(
+    examples: &[Example],
+    part: u8,
+    func: impl Fn(&str) -> Option<T>,
+) -> (usize, Vec<Mismatch>) {
+    let mut checked = 0;
+    let mut mismatches = vec![];
+
+    for example in examples {
+        let Some(expected) = example.expected.get(part) else {
+            continue;
+        };
+
+        checked += 1;
+        let actual = func(&example.input).map(|x| x.to_string());
+
+        if actual.as_deref() != Some(expected) {
+            mismatches.push(Mismatch {
+                example: example.name.clone(),
+                expected: expected.into(),
+                actual,
+            });
+        }
+    }
+
+    (checked, mismatches)
+}
+
+/// Asserts that a part solves every example of the day that has an expected answer for it.
+/// Meant to be called from the tests of a day's module.
+///
+/// # Panics
+/// If the examples cannot be read or any answer is wrong.
+pub fn assert_part<T: Display>(puzzle: PuzzleId, part: u8, func: impl Fn(&str) -> Option<T>) {
+    let examples = read(puzzle).unwrap_or_else(|e| panic!("puzzle {puzzle}: {e}"));
+    let (checked, mismatches) = check(&examples, part, func);
+
+    if checked == 0 {
+        eprintln!(
+            "Puzzle {puzzle} has no example answers for part {part}, add them to \"{}\".",
+            get_manifest_path(puzzle)
+        );
+    }
+
+    let report: Vec<String> = mismatches
+        .iter()
+        .map(|m| {
+            let actual = m.actual.as_deref().unwrap_or("no answer");
+            format!(
+                "example \"{}\": expected {}, got {actual}",
+                m.example, m.expected
+            )
+        })
+        .collect();
+
+    assert!(
+        report.is_empty(),
+        "part {part} failed on {} of {checked} example(s):\n{}",
+        report.len(),
+        report.join("\n")
+    );
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        check, from_puzzle, parse_manifest, serialize_manifest, Example, Mismatch,
+        MANIFEST_TEMPLATE,
+    };
+    use crate::template::answers::Answers;
+
+    fn example(name: &str, input: &str, part_1: Option<&str>, part_2: Option<&str>) -> Example {
+        Example {
+            name: name.into(),
+            input: input.into(),
+            expected: Answers {
+                part_1: part_1.map(String::from),
+                part_2: part_2.map(String::from),
+            },
+        }
+    }
+
+    #[test]
+    fn parses_manifest() {
+        let manifest =
+            parse_manifest("[one]\npart_1 = \"2\"\n\n[ghosts]\npart_2 = \"6\"\n\n[empty]\n")
+                .unwrap();
+
+        let names: Vec<&str> = manifest.keys().map(String::as_str).collect();
+        assert_eq!(names, ["empty", "ghosts", "one"]);
+        assert_eq!(manifest["one"].get(1), Some("2"));
+        assert_eq!(manifest["ghosts"].get(1), None);
+        assert!(manifest["empty"].is_empty());
+
+        assert!(parse_manifest("[one]\npart_1 = 2\n").is_err());
+    }
+
+    #[test]
+    fn template_has_no_answers() {
+        let manifest = parse_manifest(MANIFEST_TEMPLATE).unwrap();
+        assert!(manifest["example"].is_empty());
+    }
+
+    #[test]
+    fn checks_examples_of_part() {
+        let examples = [
+            example("a", "1 2", Some("3"), None),
+            example("b", "4 5", Some("9"), Some("20")),
+            example("c", "2 2", Some("5"), None),
+        ];
+        let sum =
+            |input: &str| -> Option<u32> { input.split(' ').map(|x| x.parse::<u32>().ok()).sum() };
+
+        let (checked, mismatches) = check(&examples, 1, sum);
+        assert_eq!(checked, 3);
+        assert_eq!(
+            mismatches,
+            vec![Mismatch {
+                example: "c".into(),
+                expected: "5".into(),
+                actual: Some("4".into())
+            }]
+        );
+
+        let (checked, mismatches) = check(&examples, 2, |_: &str| None::<u32>);
+        assert_eq!(checked, 1);
+        assert_eq!(mismatches[0].actual, None);
+    }
+
+    #[test]
+    fn round_trips_manifest() {
+        let mut manifest = parse_manifest(MANIFEST_TEMPLATE).unwrap();
+        manifest.insert("ghosts".into(), example("", "", None, Some("6")).expected);
+
+        let s = serialize_manifest(&manifest).unwrap();
+        assert!(s.starts_with("# Expected answers"));
+        assert_eq!(parse_manifest(&s).unwrap(), manifest);
+    }
+
+    const PUZZLE: &str = r#"<h2>--- Day 9: Mirage Maintenance ---</h2><p>For example:</p>
+<pre><code>0 3 6 9 12 15
+1 3 &lt;6 10 15 21
+</code></pre>
+<p>The next value is <code><em>18</em></code>, adding them gives <code><em>114</em></code>.</p>
+
+<h2 id="part2">--- Part Two ---</h2><p>Extrapolating backwards gives <code><em>2</em></code>.</p>"#;
+
+    #[test]
+    fn extracts_shared_example() {
+        let examples = from_puzzle(PUZZLE);
+
+        assert_eq!(
+            examples,
+            vec![example(
+                "example",
+                "0 3 6 9 12 15\n1 3 <6 10 15 21\n",
+                Some("114"),
+                Some("2")
+            )]
+        );
+    }
+
+    #[test]
+    fn extracts_example_per_part() {
+        let puzzle = PUZZLE.replace(
+            "<p>Extrapolating",
+            "<pre><code><em>1</em> 2 3\n</code></pre><p>Extrapolating",
+        );
+        let examples = from_puzzle(&puzzle);
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].expected.get(2), None);
+        assert_eq!(
+            examples[1],
+            example("example_2", "1 2 3\n", None, Some("2"))
+        );
+    }
+
+    #[test]
+    fn extracts_part_one_only() {
+        let puzzle = &PUZZLE[..PUZZLE.find("<h2 id").unwrap()];
+        let examples = from_puzzle(puzzle);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].expected.get(1), Some("114"));
+        assert!(from_puzzle("<p>no puzzle</p>").is_empty());
+    }
+}
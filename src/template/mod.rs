@@ -1,37 +1,251 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{PuzzleId, Year};
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::io::Read;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::{env, fs, io, process};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod answers;
+pub mod aoc_client;
+pub mod bench_history;
+pub mod charts;
 pub mod commands;
+pub mod examples;
+pub mod readme;
 pub mod readme_benchmarks;
+pub mod report;
 pub mod runner;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle, e.g. `data/2023/inputs/05.txt`, to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    try_read_file(folder, puzzle).expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Like [`read_file`], but returns an error instead of panicking if the file cannot be read.
+pub fn try_read_file(folder: &str, puzzle: PuzzleId) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    fs::read_to_string(cwd.join(puzzle.data_path(folder, "txt")))
+}
+
+/// The arguments that select a puzzle on the command line, e.g. `5 --year 2022`.
+/// The year is left out if it is the default year of the workspace.
+#[must_use]
+pub fn puzzle_args(puzzle: PuzzleId) -> String {
+    if Year::from_env() == Some(puzzle.year) {
+        puzzle.day.into_inner().to_string()
+    } else {
+        format!("{} --year {}", puzzle.day.into_inner(), puzzle.year)
+    }
+}
+
+/// Where a day's binary reads its input from, selected with `--input` or `--example`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/YYYY/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/YYYY/examples`, or the first named example of the day.
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the source passed to a day's binary by `solve`.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        Self::from_arg_list(&args)
+    }
+
+    fn from_arg_list(args: &[String]) -> Self {
+        if args.iter().any(|x| x == "--example") {
+            return Self::Example;
+        }
+
+        match args.iter().position(|x| x == "--input") {
+            Some(i) => match args.get(i + 1) {
+                // NOTE: parsing an input source cannot fail.
+                Some(path) => path.parse().unwrap(),
+                None => {
+                    eprintln!("Unexpected command-line input. Format: cargo solve 1 --input path");
+                    process::exit(1);
+                }
+            },
+            None => Self::Puzzle,
+        }
+    }
+
+    /// The arguments that select this source on a day's binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example => vec!["--example".into()],
+            Self::File(path) => vec!["--input".into(), path.display().to_string()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    pub fn read(&self, puzzle: PuzzleId) -> io::Result<String> {
+        match self {
+            Self::Puzzle => try_read_file("inputs", puzzle),
+            Self::Example => match try_read_file("examples", puzzle) {
+                Err(e) if e.kind() == io::ErrorKind::NotFound => examples::read(puzzle)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?
+                    .into_iter()
+                    .next()
+                    .map(|example| example.input)
+                    .ok_or(e),
+                result => result,
+            },
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Puzzle => f.write_str("puzzle input"),
+            Self::Example => f.write_str("example input"),
+            Self::File(path) => write!(f, "input file \"{}\"", path.display()),
+            Self::Stdin => f.write_str("stdin"),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    /// Parses the value of `--input`, where `-` stands for stdin.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Self::Stdin),
+            path => Ok(Self::File(path.into())),
+        }
+    }
+}
+
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE`, the registry entry `SOLUTION` of the puzzle
+/// and a `main` that runs both parts on the input.
+///
+/// Days can opt into a shared parse step with `solution!(YEAR, DAY, parse)`.
+/// The input is then parsed and timed once, and both parts receive a reference to the parsed value.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@main $year:expr, $day:expr) => {
+        /// The year of the current puzzle.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::PuzzleId::new(YEAR, DAY);
 
+        #[allow(dead_code)]
         fn main() {
-            use advent_of_code::template::runner::*;
-            // Hate windows
-            let input = advent_of_code::template::read_file("inputs", DAY).replace("\r", "");
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let source = advent_of_code::template::InputSource::from_args();
+            let input = match source.read(PUZZLE) {
+                // Hate windows
+                Ok(input) => input.replace("\r", ""),
+                Err(e) => {
+                    eprintln!("Could not read {source}: {e}");
+                    std::process::exit(1);
+                }
+            };
+            let options = advent_of_code::template::runner::RunOptions::from_args();
+            let reports = (SOLUTION.run)(&input, &options);
+
+            if reports.iter().any(|report| report.error.is_some()) {
+                std::process::exit(1);
+            }
         }
     };
+    ($year:literal, $day:literal) => {
+        $crate::solution!(@main $year, $day);
+
+        /// The current puzzle's entry in the solution registry.
+        pub const SOLUTION: advent_of_code::solutions::Solution =
+            advent_of_code::solutions::Solution {
+                puzzle: PUZZLE,
+                part_one: |input| part_one(input).map(|x| x.to_string()),
+                part_two: |input| part_two(input).map(|x| x.to_string()),
+                run: |input, options| {
+                    use advent_of_code::template::runner::*;
+                    use std::sync::Arc;
+                    // shared, so that a part that timed out can be left running on its own thread.
+                    let input: Arc<str> = input.into();
+                    vec![
+                        run_part(|input: Arc<str>| part_one(&input), input.clone(), PUZZLE, 1, options),
+                        run_part(|input: Arc<str>| part_two(&input), input, PUZZLE, 2, options),
+                    ]
+                },
+            };
+    };
+    ($year:literal, $day:literal, $parse:ident) => {
+        $crate::solution!(@main $year, $day);
+
+        /// The current puzzle's entry in the solution registry.
+        pub const SOLUTION: advent_of_code::solutions::Solution =
+            advent_of_code::solutions::Solution {
+                puzzle: PUZZLE,
+                part_one: |input| part_one(&$parse(input)).map(|x| x.to_string()),
+                part_two: |input| part_two(&$parse(input)).map(|x| x.to_string()),
+                run: |input, options| {
+                    use advent_of_code::template::runner::*;
+                    use std::sync::Arc;
+                    // shared, so that a part that timed out can be left running on its own thread.
+                    let input: Arc<str> = input.into();
+                    let (parsed, parse_report) =
+                        run_parse(|input: Arc<str>| $parse(&input), input, PUZZLE, options);
+                    let Some(parsed) = parsed.map(Arc::new) else {
+                        return vec![parse_report];
+                    };
+                    vec![
+                        parse_report,
+                        run_part(|parsed: Arc<_>| part_one(&parsed), parsed.clone(), PUZZLE, 1, options),
+                        run_part(|parsed: Arc<_>| part_two(&parsed), parsed, PUZZLE, 2, options),
+                    ]
+                },
+            };
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    #[test]
+    fn parses_input_source() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "data/friend/07.txt".parse(),
+            Ok(InputSource::File("data/friend/07.txt".into()))
+        );
+    }
+
+    #[test]
+    fn input_source_round_trip() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example,
+            InputSource::File("edge case.txt".into()),
+            InputSource::Stdin,
+        ] {
+            assert_eq!(InputSource::from_arg_list(&source.to_args()), source);
+        }
+    }
 }
@@ -0,0 +1,307 @@
+/// Module that generates sections of the readme from local data, without any network access.
+/// Titles are read from the downloaded puzzles, stars from the stored answers and the submission log,
+/// and timings from the latest run in the benchmark history.
+/// Every section is placed at its marker like the benchmarking table, see [`Section`].
+use std::{fs, time::Duration};
+
+use crate::template::{
+    answers::{self, Answers},
+    aoc_client,
+    bench_history::{self, DayEntry},
+    readme_benchmarks::{get_path_for_bin, locate_section, Error},
+    report::DurationReport,
+    submissions::{self, Submission, Verdict},
+};
+use crate::{all_days, PuzzleId, Year};
+
+/// A generated section of the readme, sections without a marker in the readme are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    /// A single line with the number of stars earned.
+    Progress,
+    /// A table of the days with their titles, stars, timings and links.
+    Days,
+}
+
+impl Section {
+    pub const ALL: [Section; 2] = [Section::Progress, Section::Days];
+
+    #[must_use]
+    pub fn marker(self) -> &'static str {
+        match self {
+            Section::Progress => "<!--- advent_readme_progress --->",
+            Section::Days => "<!--- advent_readme_days --->",
+        }
+    }
+}
+
+/// Everything the readme shows about a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayRow {
+    pub puzzle: PuzzleId,
+    /// Title of the puzzle, if it was downloaded.
+    pub title: Option<String>,
+    /// Whether the star of each part was earned.
+    pub stars: [bool; 2],
+    /// Timings of the latest benchmark run.
+    pub timings: Option<DayEntry>,
+    pub has_solution: bool,
+}
+
+impl DayRow {
+    fn star_count(&self) -> usize {
+        self.stars.iter().filter(|&&star| star).count()
+    }
+}
+
+/// Whether the star of each part was earned, by a stored answer or a correct submission.
+fn earned_stars(answers: &Answers, log: &[Submission]) -> [bool; 2] {
+    [1, 2].map(|part| {
+        answers.get(part).is_some()
+            || log
+                .iter()
+                .any(|s| s.part == part && s.verdict == Verdict::Correct)
+    })
+}
+
+/// The second part of the last day has no answer, its star is awarded once all other stars are earned.
+fn award_last_star(rows: &mut [DayRow]) {
+    let others = rows.iter().map(DayRow::star_count).sum::<usize>();
+
+    if let Some(last) = rows.iter_mut().find(|r| r.puzzle.day.into_inner() == 25) {
+        if !last.stars[1] && others == 49 && last.stars[0] {
+            last.stars[1] = true;
+        }
+    }
+}
+
+/// Collects the days of a year that have a solution, a downloaded puzzle or a star.
+#[must_use]
+pub fn collect(year: Year) -> Vec<DayRow> {
+    let latest = bench_history::read(year)
+        .ok()
+        .and_then(|history| history.last().cloned());
+
+    let mut rows: Vec<DayRow> = all_days()
+        .map(|day| {
+            let puzzle = PuzzleId::new(year, day);
+            let answers = answers::read(puzzle).unwrap_or_default();
+            let log = submissions::read(puzzle).unwrap_or_default();
+
+            DayRow {
+                puzzle,
+                title: fs::read_to_string(aoc_client::get_puzzle_path(puzzle))
+                    .ok()
+                    .and_then(|description| aoc_client::puzzle_title(&description)),
+                stars: earned_stars(&answers, &log),
+                timings: latest
+                    .as_ref()
+                    .and_then(|run| run.days.iter().find(|d| d.day == day.into_inner()))
+                    .cloned(),
+                has_solution: crate::solutions::get(puzzle).is_some(),
+            }
+        })
+        .filter(|row| row.has_solution || row.title.is_some() || row.star_count() > 0)
+        .collect();
+
+    award_last_star(&mut rows);
+    rows
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn format_timing(report: Option<&DurationReport>) -> String {
+    report.map_or_else(
+        || "-".into(),
+        |r| format!("`{:.1?}`", Duration::from_nanos(r.median_ns)),
+    )
+}
+
+fn construct_progress(rows: &[DayRow]) -> String {
+    let stars: usize = rows.iter().map(DayRow::star_count).sum();
+    let marker = Section::Progress.marker();
+
+    format!("{marker}\n**Progress:** ⭐ {stars} / 50\n{marker}")
+}
+
+fn construct_days(rows: &[DayRow]) -> String {
+    let marker = Section::Days.marker();
+
+    let mut lines: Vec<String> = vec![
+        marker.into(),
+        "| Day | Title | Stars | Part 1 | Part 2 | Links |".into(),
+        "| :---: | :--- | :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    for row in rows {
+        let mut links = vec![format!(
+            "[Puzzle]({})",
+            aoc_client::get_puzzle_url(row.puzzle)
+        )];
+        if row.has_solution {
+            links.push(format!("[Source]({})", get_path_for_bin(row.puzzle)));
+        }
+
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} | {} |",
+            row.puzzle.day.into_inner(),
+            row.title.as_deref().unwrap_or("-").replace('|', "\\|"),
+            "⭐".repeat(row.star_count()),
+            format_timing(row.timings.as_ref().and_then(|t| t.part_1.as_ref())),
+            format_timing(row.timings.as_ref().and_then(|t| t.part_2.as_ref())),
+            links.join(" · ")
+        ));
+    }
+
+    lines.push(marker.into());
+    lines.join("\n")
+}
+
+/// Replaces every section that has a marker in the readme, returns the number of replaced sections.
+fn update_content(s: &mut String, rows: &[DayRow]) -> Result<usize, Error> {
+    let mut updated = 0;
+
+    for section in Section::ALL {
+        if !s.contains(section.marker()) {
+            continue;
+        }
+
+        let content = match section {
+            Section::Progress => construct_progress(rows),
+            Section::Days => construct_days(rows),
+        };
+
+        let positions = locate_section(s, section.marker())?;
+        s.replace_range(positions.pos_start..positions.pos_end, &content);
+        updated += 1;
+    }
+
+    Ok(updated)
+}
+
+/// Regenerates the sections of the readme for a year, returns the number of updated sections.
+pub fn update(year: Year) -> Result<usize, Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let updated = update_content(&mut readme, &collect(year))?;
+
+    if updated > 0 {
+        fs::write(path, &readme)?;
+    }
+    Ok(updated)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{award_last_star, earned_stars, update_content, DayRow, Section};
+    use crate::template::{
+        answers::Answers,
+        bench_history::DayEntry,
+        report::DurationReport,
+        submissions::{Submission, Verdict},
+    };
+    use crate::{day, year, PuzzleId};
+
+    fn row(day: u8, stars: [bool; 2], title: Option<&str>) -> DayRow {
+        DayRow {
+            puzzle: PuzzleId::new(year!(2023), crate::Day::new(day).unwrap()),
+            title: title.map(String::from),
+            stars,
+            timings: None,
+            has_solution: true,
+        }
+    }
+
+    #[test]
+    fn stars_from_answers_and_log() {
+        let answers = Answers {
+            part_1: Some("35".into()),
+            part_2: None,
+        };
+        assert_eq!(earned_stars(&answers, &[]), [true, false]);
+
+        let mut submission = Submission {
+            part: 2,
+            answer: "46".into(),
+            verdict: Verdict::TooHigh,
+            timestamp: 0,
+            wait_secs: None,
+        };
+        assert_eq!(
+            earned_stars(&Answers::default(), &[submission.clone()]),
+            [false, false]
+        );
+
+        submission.verdict = Verdict::Correct;
+        assert_eq!(
+            earned_stars(&Answers::default(), &[submission]),
+            [false, true]
+        );
+    }
+
+    #[test]
+    fn awards_last_star() {
+        let mut rows: Vec<DayRow> = (1..=25).map(|d| row(d, [true, d != 25], None)).collect();
+        award_last_star(&mut rows);
+        assert_eq!(rows[24].stars, [true, true]);
+
+        let mut rows: Vec<DayRow> = (1..=25).map(|d| row(d, [true, d < 24], None)).collect();
+        award_last_star(&mut rows);
+        assert_eq!(rows[24].stars, [true, false]);
+    }
+
+    #[test]
+    fn updates_marked_sections() {
+        let mut first = row(1, [true, true], Some("Trebuchet?!"));
+        first.timings = Some(DayEntry {
+            day: 1,
+            parse: None,
+            part_1: Some(DurationReport {
+                min_ns: 0,
+                median_ns: 22_800,
+                p95_ns: 0,
+                std_dev_ns: 0,
+                outliers: 0,
+            }),
+            part_2: None,
+        });
+        let mut second = row(2, [true, false], Some("Cube | Conundrum"));
+        second.has_solution = false;
+        assert_eq!(second.puzzle.day, day!(2));
+
+        let marker = Section::Days.marker();
+        let mut s = format!("# readme\n{marker}\n\nfoo");
+        assert_eq!(update_content(&mut s, &[first, second]).unwrap(), 1);
+
+        let expected = [
+            "# readme",
+            marker,
+            "| Day | Title | Stars | Part 1 | Part 2 | Links |",
+            "| :---: | :--- | :---: | :---: | :---: | :---: |",
+            "| 1 | Trebuchet?! | ⭐⭐ | `22.8µs` | - | [Puzzle](https://adventofcode.com/2023/day/1) · [Source](./src/bin/2023_01.rs) |",
+            "| 2 | Cube \\| Conundrum | ⭐ | - | - | [Puzzle](https://adventofcode.com/2023/day/2) |",
+            marker,
+            "",
+            "foo",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+
+        // regenerating replaces the section instead of adding another one.
+        let rows = [row(1, [true, true], None)];
+        let progress = Section::Progress.marker();
+        s.push_str(&format!("\n{progress}{progress}"));
+        assert_eq!(update_content(&mut s, &rows).unwrap(), 2);
+        assert_eq!(s.matches(marker).count(), 2);
+        assert!(s.ends_with(&format!("{progress}\n**Progress:** ⭐ 2 / 50\n{progress}")));
+    }
+
+    #[test]
+    fn skips_readme_without_markers() {
+        let mut s = "# readme".to_string();
+        assert_eq!(update_content(&mut s, &[]).unwrap(), 0);
+        assert_eq!(s, "# readme");
+    }
+}
@@ -2,7 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::runner::BenchStats;
+use crate::PuzzleId;
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -18,31 +19,45 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not update README: {e}"),
+            Error::IO(e) => write!(f, "could not access README: {e}"),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub puzzle: PuzzleId,
+    /// The shared parse step, only present for days that opt into it.
+    pub parse: Option<BenchStats>,
+    pub part_1: Option<BenchStats>,
+    pub part_2: Option<BenchStats>,
+    /// Sum of the median run times of the parse step and both parts.
     pub total_nanos: f64,
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub(crate) pos_start: usize,
+    pub(crate) pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Finds the section between the first and last occurrence of `marker`, including the markers.
+/// A single marker is an empty section.
+pub(crate) fn locate_section(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
@@ -58,73 +73,108 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+fn format_timing(stats: Option<&BenchStats>) -> String {
+    stats.map_or_else(|| "-".into(), |s| format!("{:?}", s.median))
+}
+
+fn construct_table(
+    prefix: &str,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    charts: &[String],
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(timing.puzzle);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |",
+            timing.puzzle.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_timing(timing.parse.as_ref()),
+            format_timing(timing.part_1.as_ref()),
+            format_timing(timing.part_2.as_ref())
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
+
+    for chart in charts {
+        lines.push(String::new());
+        lines.push(format!("![Benchmark chart](./{chart})"));
+    }
+
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    charts: &[String],
+) -> Result<(), Error> {
+    let positions = locate_section(s, MARKER)?;
+    let table = construct_table("##", timings, total_millis, charts);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+/// Replaces the benchmarking table, `charts` are paths of images that are embedded below the table.
+pub fn update(timings: Vec<Timings>, total_millis: f64, charts: &[String]) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings, total_millis, charts)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
+    use std::time::Duration;
+
     use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use crate::template::runner::BenchStats;
+    use crate::{day, year, PuzzleId};
+
+    fn mock_stats(millis: u64) -> Option<BenchStats> {
+        Some(BenchStats {
+            median: Duration::from_millis(millis),
+            ..BenchStats::default()
+        })
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
+                parse: None,
+                part_1: mock_stats(10),
+                part_2: mock_stats(20),
                 total_nanos: 3e+10,
             },
             Timings {
-                day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
+                puzzle: PuzzleId::new(year!(2023), day!(2)),
+                parse: mock_stats(5),
+                part_1: mock_stats(30),
+                part_2: mock_stats(40),
                 total_nanos: 7e+10,
             },
             Timings {
-                day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
+                puzzle: PuzzleId::new(year!(2023), day!(4)),
+                parse: None,
+                part_1: mock_stats(40),
+                part_2: mock_stats(50),
                 total_nanos: 9e+10,
             },
         ]
@@ -134,28 +184,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,18 +213,18 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &[]).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023_01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023_02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023_04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +233,14 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn embeds_charts() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let charts = [".assets/benchmarks_2023.svg".to_string()];
+        update_content(&mut s, get_mock_timings(), 190.0, &charts).unwrap();
+        assert!(s.ends_with(
+            "**Total: 190.00ms**\n\n![Benchmark chart](./.assets/benchmarks_2023.svg)\n<!--- benchmarking table --->"
+        ));
+    }
 }
@@ -0,0 +1,287 @@
+/// Machine-readable records of executed solution parts.
+/// In `--format json` mode every part prints one record as a single line of JSON.
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::alloc::AllocStats;
+use crate::template::runner::{BenchStats, Failure};
+use crate::Day;
+
+/// How results of a solution are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-oriented console output.
+    #[default]
+    Text,
+    /// One [`PartReport`] per line.
+    Json,
+}
+
+impl OutputFormat {
+    /// The format requested via `--format` on the command line, [`OutputFormat::Text`] if absent.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        args.iter()
+            .position(|x| x == "--format")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|x| x.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => f.write_str("text"),
+            OutputFormat::Json => f.write_str("json"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of either \"text\" or \"json\"")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Run time statistics of a part, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurationReport {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub std_dev_ns: u64,
+    pub outliers: usize,
+}
+
+impl From<&BenchStats> for DurationReport {
+    fn from(stats: &BenchStats) -> Self {
+        Self {
+            min_ns: duration_to_nanos(stats.min),
+            median_ns: duration_to_nanos(stats.median),
+            p95_ns: duration_to_nanos(stats.p95),
+            std_dev_ns: duration_to_nanos(stats.std_dev),
+            outliers: stats.outliers,
+        }
+    }
+}
+
+/// The outcome of running a single part of a day's solution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartReport {
+    pub day: u8,
+    /// The part number, [`PARSE_PART`] for the shared parse step of a day.
+    pub part: u8,
+    /// The answer as displayed, [`None`] if the part returned no result.
+    pub answer: Option<String>,
+    pub success: bool,
+    /// Why the part did not finish, e.g. `timeout` or `panicked: msg`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub duration: DurationReport,
+    pub samples: u128,
+    /// Heap usage of the first run, only recorded with the `alloc_stats` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<AllocStats>,
+}
+
+/// Part number used in reports of the shared parse step of a day.
+pub const PARSE_PART: u8 = 0;
+
+impl PartReport {
+    /// Report of the shared parse step of a day, which has no answer.
+    #[must_use]
+    pub fn parse(day: Day, stats: &BenchStats) -> Self {
+        Self {
+            day: day.into_inner(),
+            part: PARSE_PART,
+            answer: None,
+            success: true,
+            error: None,
+            duration: stats.into(),
+            samples: stats.samples,
+            alloc: None,
+        }
+    }
+
+    /// Report of a part or parse step that did not finish.
+    #[must_use]
+    pub fn failure(day: Day, part: u8, failure: Failure) -> Self {
+        Self {
+            day: day.into_inner(),
+            part,
+            answer: None,
+            success: false,
+            error: Some(failure.to_string()),
+            duration: (&BenchStats::default()).into(),
+            samples: 0,
+            alloc: None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE_PART
+    }
+
+    #[must_use]
+    pub fn new(day: Day, part: u8, answer: Option<String>, stats: &BenchStats) -> Self {
+        Self {
+            day: day.into_inner(),
+            part,
+            success: answer.is_some(),
+            answer,
+            error: None,
+            duration: stats.into(),
+            samples: stats.samples,
+            alloc: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_alloc(self, alloc: Option<AllocStats>) -> Self {
+        Self { alloc, ..self }
+    }
+
+    /// Parses a record from a line of output, returns [`None`] for any other line.
+    #[must_use]
+    pub fn from_line(line: &str) -> Option<Self> {
+        serde_json::from_str(line.trim()).ok()
+    }
+
+    /// Serializes the record as a single line of JSON.
+    #[must_use]
+    pub fn to_line(&self) -> String {
+        // NOTE: serializing a struct of plain fields into a string cannot fail.
+        serde_json::to_string(self).unwrap()
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> BenchStats {
+        BenchStats {
+            min: Duration::from_nanos(self.duration.min_ns),
+            median: Duration::from_nanos(self.duration.median_ns),
+            p95: Duration::from_nanos(self.duration.p95_ns),
+            std_dev: Duration::from_nanos(self.duration.std_dev_ns),
+            outliers: self.duration.outliers,
+            samples: self.samples,
+        }
+    }
+}
+
+fn duration_to_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{OutputFormat, PartReport};
+    use crate::day;
+    use crate::template::alloc::AllocStats;
+    use crate::template::runner::{BenchStats, Failure};
+
+    #[test]
+    fn parses_output_format() {
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn report_round_trip() {
+        let stats = BenchStats {
+            min: Duration::from_micros(70),
+            median: Duration::from_micros(74),
+            p95: Duration::from_micros(80),
+            std_dev: Duration::from_micros(2),
+            outliers: 3,
+            samples: 100,
+        };
+        let report = PartReport::new(day!(5), 2, Some("a\nb".into()), &stats);
+        let line = report.to_line();
+
+        assert!(!line.contains('\n'));
+        assert_eq!(PartReport::from_line(&line), Some(report.clone()));
+        assert_eq!(report.stats(), stats);
+        assert!(report.success);
+    }
+
+    #[test]
+    fn parse_report_has_no_answer() {
+        let report = PartReport::parse(day!(12), &BenchStats::single(Duration::from_micros(3)));
+        assert!(report.is_parse());
+        assert!(report.success);
+        assert_eq!(report.answer, None);
+        assert_eq!(PartReport::from_line(&report.to_line()), Some(report));
+    }
+
+    #[test]
+    fn ignores_other_lines() {
+        assert_eq!(PartReport::from_line("Part 1: 42 (1.0ms)"), None);
+        assert_eq!(PartReport::from_line("[1, 2, 3]"), None);
+    }
+
+    #[test]
+    fn reads_failed_part() {
+        let report = PartReport::from_line(
+            r#"{"day":1,"part":2,"answer":null,"success":false,"duration":{"min_ns":5,"median_ns":5,"p95_ns":5,"std_dev_ns":0,"outliers":0},"samples":1}"#,
+        )
+        .unwrap();
+        assert_eq!(report.answer, None);
+        assert!(!report.success);
+        assert_eq!(report.error, None);
+        assert_eq!(report.stats(), BenchStats::single(Duration::from_nanos(5)));
+    }
+
+    #[test]
+    fn alloc_round_trip() {
+        let alloc = AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 1024,
+        };
+        let report = PartReport::new(day!(12), 1, Some("21".into()), &BenchStats::default())
+            .with_alloc(Some(alloc));
+
+        assert!(report.to_line().contains(r#""alloc":{"allocations":12"#));
+        assert_eq!(PartReport::from_line(&report.to_line()), Some(report));
+    }
+
+    #[test]
+    fn failure_round_trip() {
+        let report = PartReport::failure(day!(8), 1, Failure::Timeout);
+        assert!(!report.success);
+        assert_eq!(report.error.as_deref(), Some("timeout"));
+        assert!(report.to_line().contains(r#""error":"timeout""#));
+        assert_eq!(PartReport::from_line(&report.to_line()), Some(report));
+    }
+}
@@ -1,63 +1,382 @@
-/// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+//! Encapsulates code that interacts with solution functions.
+use crate::template::alloc::{self, AllocStats};
+use crate::template::aoc_client::AocClient;
+use crate::template::report::{OutputFormat, PartReport, PARSE_PART};
+use crate::template::submissions::{self, Submission, Verdict};
+use crate::template::{answers, ANSI_ITALIC, ANSI_RESET};
+use crate::PuzzleId;
+use std::any::Any;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::num::ParseIntError;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Options that control how a solution is run, usually read from the arguments of a day's binary.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    pub format: OutputFormat,
+    /// Bench each part instead of running it once.
+    pub is_timed: bool,
+    /// The part whose result is submitted after running it.
+    pub submit: Option<u8>,
+    /// Only collect reports instead of printing them, used when days run concurrently.
+    pub is_quiet: bool,
+    /// Wall-clock limit of the first run of each part, [`None`] to wait forever.
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// Reads the options passed to a day's binary by `solve`.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        Self {
+            format: OutputFormat::from_args(),
+            is_timed: args.iter().any(|x| x == "--time"),
+            submit: parse_submit_arg(&args),
+            is_quiet: false,
+            timeout: parse_timeout_arg(&args),
+        }
+    }
+}
+
+/// Timeout of a part when running all days, if no `--timeout` is given.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Stack size of the threads that run solutions, matching the main thread on most platforms.
+pub const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Parses a timeout in seconds, where `0` disables the timeout.
+pub fn parse_timeout(s: &str) -> Result<Option<Duration>, ParseIntError> {
+    let secs: u64 = s.parse()?;
+    Ok((secs > 0).then(|| Duration::from_secs(secs)))
+}
+
+/// Parse the `--timeout` argument passed to `solve`, exits on malformed input.
+fn parse_timeout_arg(args: &[String]) -> Option<Duration> {
+    let secs_index = args.iter().position(|x| x == "--timeout")? + 1;
+
+    match args.get(secs_index).map(|x| parse_timeout(x)) {
+        Some(Ok(timeout)) => timeout,
+        _ => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --timeout 60");
+            process::exit(1);
+        }
+    }
+}
+
+/// Parse the `--submit` argument passed to `solve`, exits on malformed input.
+fn parse_submit_arg(args: &[String]) -> Option<u8> {
+    let part_index = args.iter().position(|x| x == "--submit")? + 1;
+
+    match args.get(part_index).map(|x| x.parse::<u8>()) {
+        Some(Ok(part)) => Some(part),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        }
+    }
+}
+
+/// Runs and prints a part of a solution.
+/// A part that panics or exceeds [`RunOptions::timeout`] is reported as failed instead of aborting the run.
+pub fn run_part<I, T>(
+    func: impl Fn(I) -> Option<T> + Clone + Send + 'static,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport
+where
+    I: Clone + Send + 'static,
+    T: Display + Send + 'static,
+{
     let part_str = format!("Part {part}");
+    let is_text = !options.is_quiet && options.format == OutputFormat::Text;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, stats, alloc) = match run_timed(func, input, options, is_text, |result| {
+        if is_text {
+            print_result(result, &part_str, "");
+        }
+    }) {
+        Ok(run) => run,
+        Err(failure) => {
+            return report_failure(PartReport::failure(puzzle.day, part, failure), options)
+        }
+    };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let report = PartReport::new(
+        puzzle.day,
+        part,
+        result.as_ref().map(ToString::to_string),
+        &stats,
+    )
+    .with_alloc(alloc);
+
+    match options.format {
+        _ if options.is_quiet => {}
+        OutputFormat::Text => print_result(&result, &part_str, &format_stats(&stats, alloc)),
+        OutputFormat::Json => println!("{}", report.to_line()),
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if options.submit == Some(part) {
+            submit_result(result, puzzle, part, options.format);
+        }
     }
+
+    report
 }
 
+/// Run the `parse` step of a puzzle that shares its parsed input between both parts.
+/// The step is timed like a part, its output is handed to [`run_part`] for each part.
+/// Returns [`None`] if the step failed, in which case the parts cannot run.
+pub fn run_parse<I, P>(
+    func: impl Fn(I) -> P + Clone + Send + 'static,
+    input: I,
+    puzzle: PuzzleId,
+    options: &RunOptions,
+) -> (Option<P>, PartReport)
+where
+    I: Clone + Send + 'static,
+    P: Send + 'static,
+{
+    let is_text = !options.is_quiet && options.format == OutputFormat::Text;
+
+    let (parsed, stats, alloc) = match run_timed(func, input, options, is_text, |_| {
+        if is_text {
+            print!("{PARSE_LABEL}:");
+        }
+    }) {
+        Ok(run) => run,
+        Err(failure) => {
+            let report = PartReport::failure(puzzle.day, PARSE_PART, failure);
+            return (None, report_failure(report, options));
+        }
+    };
+
+    let report = PartReport::parse(puzzle.day, &stats).with_alloc(alloc);
+
+    match options.format {
+        _ if options.is_quiet => {}
+        OutputFormat::Text => println!("\r{PARSE_LABEL}:{}", format_stats(&stats, alloc)),
+        OutputFormat::Json => println!("{}", report.to_line()),
+    }
+
+    (Some(parsed), report)
+}
+
+fn report_failure(report: PartReport, options: &RunOptions) -> PartReport {
+    match options.format {
+        _ if options.is_quiet => {}
+        OutputFormat::Text => print_report(&report),
+        OutputFormat::Json => println!("{}", report.to_line()),
+    }
+
+    report
+}
+
+const PARSE_LABEL: &str = "Parse";
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+///  2. in release, the function is warmed up and then benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// Only the first execution is guarded by [`run_guarded`], a part that passed it is trusted during the bench.
+/// The allocations of the first execution are counted, see [`alloc::measure`].
+fn run_timed<I, T>(
+    func: impl Fn(I) -> T + Clone + Send + 'static,
     input: I,
+    options: &RunOptions,
+    show_progress: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+) -> Result<(T, BenchStats, Option<AllocStats>), Failure>
+where
+    I: Clone + Send + 'static,
+    T: Send + 'static,
+{
+    let guarded = func.clone();
+    let ((result, alloc), base_time) = run_guarded(
+        move |input| alloc::measure(|| guarded(input)),
+        input.clone(),
+        options.timeout,
+    )?;
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if options.is_timed {
+        bench(func, input, &base_time, show_progress)
     } else {
-        (base_time, 1)
+        BenchStats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    Ok((result, stats, alloc))
+}
+
+/// Why a part did not produce a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Timeout,
+    Panic(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Timeout => f.write_str("timeout"),
+            Failure::Panic(msg) => write!(f, "panicked: {msg}"),
+        }
+    }
+}
+
+/// Runs the function once, catching panics.
+/// With a timeout, the function runs on its own thread which is abandoned once the timeout expires,
+/// it keeps running in the background until the process exits.
+fn run_guarded<I, T>(
+    func: impl FnOnce(I) -> T + Send + 'static,
+    input: I,
+    timeout: Option<Duration>,
+) -> Result<(T, Duration), Failure>
+where
+    I: Send + 'static,
+    T: Send + 'static,
+{
+    let task = move || {
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            let timer = Instant::now();
+            let result = func(input);
+            (result, timer.elapsed())
+        }))
+        .map_err(|e| Failure::Panic(panic_message(e.as_ref())))
+    };
+
+    let Some(timeout) = timeout else {
+        return task();
+    };
+
+    let (tx, rx) = mpsc::channel();
+
+    let spawned = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            // the receiver is gone if the timeout already expired.
+            let _ = tx.send(task());
+        });
+
+    if let Err(e) = spawned {
+        return Err(Failure::Panic(format!("could not spawn thread: {e}")));
+    }
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(Failure::Timeout),
+        // the task catches panics, but a panic that aborts unwinding still drops the sender.
+        Err(RecvTimeoutError::Disconnected) => Err(Failure::Panic(
+            "solution thread exited without a result".into(),
+        )),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        (*msg).to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+/// Summary statistics of a solution part's run time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BenchStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    /// Samples outside of 1.5 IQR of the first and third quartile.
+    pub outliers: usize,
+    pub samples: u128,
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+impl BenchStats {
+    /// Stats for a part that was executed exactly once.
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            min: duration,
+            median: duration,
+            p95: duration,
+            std_dev: Duration::ZERO,
+            outliers: 0,
+            samples: 1,
+        }
+    }
+
+    /// Computes stats from a non-empty list of samples.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let mean = average_duration(&sorted);
+        #[allow(clippy::cast_precision_loss)]
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_nanos() as f64 - mean).powi(2))
+            .sum::<f64>()
+            / sorted.len() as f64;
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let fence = q3.saturating_sub(q1).mul_f64(1.5);
+        let outliers = sorted
+            .iter()
+            .filter(|&&d| d + fence < q1 || d > q3 + fence)
+            .count();
+
+        Self {
+            min: sorted[0],
+            median: percentile(&sorted, 50.0),
+            p95: percentile(&sorted, 95.0),
+            std_dev: Duration::from_secs_f64(variance.sqrt() / 1e9),
+            outliers,
+            samples: sorted.len() as u128,
+        }
+    }
+}
+
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    show_progress: bool,
+) -> BenchStats {
     let mut stdout = stdout();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    // warm up caches and branch predictors for ~10% of the measured runs before recording samples.
+    if show_progress {
+        print!(" > {ANSI_ITALIC}warming up{ANSI_RESET}");
+        let _ = stdout.flush();
+    }
+
+    for _ in 0..cmp::max(bench_iterations / 10, 1) {
+        func(input.clone());
+    }
+
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout.flush();
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -69,29 +388,79 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
+#[allow(clippy::cast_precision_loss)]
+fn average_duration(numbers: &[Duration]) -> f64 {
     numbers
         .iter()
         .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+        .sum::<u128>() as f64
+        / numbers.len() as f64
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty list of samples.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.median)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} ±{:.1?}, min {:.1?}, p95 {:.1?}, {} outliers @ {} samples)",
+            stats.median, stats.std_dev, stats.min, stats.p95, stats.outliers, stats.samples
+        )
     }
 }
 
+/// Durations of a part, followed by its heap usage if allocations were counted.
+fn format_stats(stats: &BenchStats, alloc: Option<AllocStats>) -> String {
+    match alloc {
+        Some(alloc) => format!("{} [{alloc}]", format_duration(stats)),
+        None => format_duration(stats),
+    }
+}
+
+/// Prints a part that was executed elsewhere in the same way as [`run_part`] does.
+pub fn print_report(report: &PartReport) {
+    if let Some(error) = &report.error {
+        let part_str = if report.is_parse() {
+            PARSE_LABEL.to_string()
+        } else {
+            format!("Part {}", report.part)
+        };
+        // NOTE: threads cannot be killed, a part that timed out keeps running in the background.
+        let note = if *error == Failure::Timeout.to_string() {
+            format!(" {ANSI_ITALIC}(left running until the process exits){ANSI_RESET}")
+        } else {
+            String::new()
+        };
+        println!("{part_str}: ✖ {error}{note}");
+        return;
+    }
+
+    if report.is_parse() {
+        println!("{PARSE_LABEL}:{}", format_stats(&report.stats(), report.alloc));
+        return;
+    }
+
+    let part_str = format!("Part {}", report.part);
+    print_result(
+        &report.answer,
+        &part_str,
+        &format_stats(&report.stats(), report.alloc),
+    );
+}
+
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
@@ -127,41 +496,150 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
+/// Cooldowns up to this long are waited out before submitting, longer ones cancel the submission.
+const MAX_COOLDOWN_WAIT: Duration = Duration::from_secs(90);
+
+/// Try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+///  2. a session cookie is configured, see [`aoc_client`].
+///  3. the answer is not known to be wrong from the submission log, see [`submissions`].
+///
+/// A correct answer is recorded in `data/YYYY/answers` so that `verify` can detect regressions.
+/// In JSON mode the status messages go to stderr, so that stdout only holds the report lines.
+fn submit_result<T: Display>(result: T, puzzle: PuzzleId, part: u8, format: OutputFormat) {
+    let status = |message: String| match format {
+        OutputFormat::Text => println!("{message}"),
+        OutputFormat::Json => eprintln!("{message}"),
+    };
 
-    if !args.contains(&"--submit".into()) {
-        return None;
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Cannot submit: {e}");
+            process::exit(1);
+        }
+    };
+
+    let answer = result.to_string();
+    let log = match submissions::read(puzzle) {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!("Cannot submit: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submissions::check(&log, part, &answer) {
+        status(format!("Not submitting {answer}: {refusal}."));
+        return;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if let Some(cooldown) = submissions::current_cooldown(&log) {
+        if cooldown > MAX_COOLDOWN_WAIT {
+            status(format!(
+                "Not submitting {answer}: wait {cooldown:?} before trying again."
+            ));
+            return;
+        }
+
+        status(format!(
+            "Waiting {cooldown:?} for the cooldown of the last submission..."
+        ));
+        thread::sleep(cooldown);
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    status(String::from("Submitting result..."));
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let message = match client.submit(puzzle, part, &answer) {
+        Ok(message) => message,
+        Err(e) => {
+            eprintln!("Failed to submit: {e}");
+            return;
+        }
     };
+    status(message.to_string());
+
+    let submission = Submission::new(part, &answer, &message);
 
-    if part_submit != part {
-        return None;
+    if let Err(e) = submissions::append(puzzle, &submission) {
+        eprintln!("Failed to log submission: {e}");
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    if submission.verdict == Verdict::Correct {
+        match answers::record(puzzle, part, &answer) {
+            Ok(()) => status(format!(
+                "🎄 Recorded answer in \"{}\".",
+                answers::get_path(puzzle)
+            )),
+            Err(e) => eprintln!("Failed to record answer: {e}"),
+        }
     }
+}
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_timeout, run_guarded, BenchStats, Failure};
+
+    #[test]
+    fn stats_from_samples() {
+        let mut samples: Vec<Duration> = (1..=20).map(Duration::from_micros).collect();
+        samples.push(Duration::from_millis(10));
+
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(11));
+        assert_eq!(stats.p95, Duration::from_micros(20));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 21);
+    }
+
+    #[test]
+    fn stats_from_constant_samples() {
+        let stats = BenchStats::from_samples(&[Duration::from_millis(3); 10]);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn guarded_run_returns_result() {
+        let (result, _) = run_guarded(|x: u32| x * 2, 21, Some(Duration::from_secs(5))).unwrap();
+        assert_eq!(result, 42);
+
+        let (result, _) = run_guarded(|x: u32| x + 1, 1, None).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn guarded_run_catches_panics() {
+        let failure = run_guarded(|x: u32| -> u32 { panic!("bad input {x}") }, 3, None);
+        assert_eq!(failure, Err(Failure::Panic("bad input 3".into())));
+
+        let failure = run_guarded(
+            |_: ()| -> u32 { panic!("static") },
+            (),
+            Some(Duration::from_secs(5)),
+        );
+        assert_eq!(failure, Err(Failure::Panic("static".into())));
+        assert_eq!(failure.unwrap_err().to_string(), "panicked: static");
+    }
+
+    #[test]
+    fn guarded_run_times_out() {
+        let failure = run_guarded(
+            |_: ()| std::thread::sleep(Duration::from_secs(2)),
+            (),
+            Some(Duration::from_millis(10)),
+        );
+        assert_eq!(failure, Err(Failure::Timeout));
+    }
+
+    #[test]
+    fn parses_timeout() {
+        assert_eq!(parse_timeout("30"), Ok(Some(Duration::from_secs(30))));
+        assert_eq!(parse_timeout("0"), Ok(None));
+        assert!(parse_timeout("-1").is_err());
+    }
 }
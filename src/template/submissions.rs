@@ -0,0 +1,384 @@
+/// Module that logs every submitted answer and the verdict of the server in `data/YYYY/submissions/DD.jsonl`.
+/// The log is used to refuse answers that are known to be wrong before they cost another cooldown.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse submission log: {e}"),
+            Error::IO(e) => write!(f, "could not access submission log: {e}"),
+        }
+    }
+}
+
+/// The verdict of the server on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked because the previous submission was too recent.
+    Cooldown,
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the message of the server.
+    #[must_use]
+    pub fn from_message(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("answer is too low") {
+            Verdict::TooLow
+        } else if message.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::Cooldown
+        } else if message.contains("Did you already complete it") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Cooldown => "not checked, cooldown",
+            Verdict::AlreadySolved => "not checked, already solved",
+            Verdict::Unknown => "unknown",
+        })
+    }
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Seconds the server asked to wait before the next submission.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_secs: Option<u64>,
+}
+
+impl Submission {
+    #[must_use]
+    pub fn new(part: u8, answer: &str, message: &str) -> Self {
+        Self {
+            part,
+            answer: answer.into(),
+            verdict: Verdict::from_message(message),
+            timestamp: now(),
+            wait_secs: parse_wait(message),
+        }
+    }
+}
+
+/// Why an answer is not sent to the server.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong(Verdict),
+    /// The answer is at least as high as an answer that was too high.
+    AboveBound(String),
+    /// The answer is at most as low as an answer that was too low.
+    BelowBound(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "the part is already solved with {answer}"),
+            Refusal::KnownWrong(verdict) => {
+                write!(f, "the answer was already submitted and was {verdict}")
+            }
+            Refusal::AboveBound(bound) => write!(f, "{bound} was already too high"),
+            Refusal::BelowBound(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("submissions", "jsonl")
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+fn parse_log(s: &str) -> Result<Vec<Submission>, Error> {
+    s.lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str(l).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+/// Reads all submissions of a puzzle, oldest first.
+pub fn read(puzzle: PuzzleId) -> Result<Vec<Submission>, Error> {
+    let path = get_path(puzzle);
+
+    if !Path::new(&path).exists() {
+        return Ok(vec![]);
+    }
+
+    parse_log(&fs::read_to_string(path)?)
+}
+
+/// Appends a submission to the log of a puzzle.
+pub fn append(puzzle: PuzzleId, submission: &Submission) -> Result<(), Error> {
+    let line = serde_json::to_string(submission).map_err(|e| Error::Parser(e.to_string()))?;
+
+    let path = get_path(puzzle);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+/// Checks an answer against the earlier submissions of the same part.
+pub fn check(log: &[Submission], part: u8, answer: &str) -> Result<(), Refusal> {
+    let submissions = log.iter().filter(|s| s.part == part);
+    let value = answer.trim().parse::<i128>().ok();
+
+    for submission in submissions {
+        if submission.verdict == Verdict::Correct {
+            return Err(Refusal::AlreadySolved(submission.answer.clone()));
+        }
+
+        if submission.verdict.is_wrong() && submission.answer == answer {
+            return Err(Refusal::KnownWrong(submission.verdict));
+        }
+
+        let (Some(value), Ok(bound)) = (value, submission.answer.trim().parse::<i128>()) else {
+            continue;
+        };
+
+        match submission.verdict {
+            Verdict::TooHigh if value >= bound => {
+                return Err(Refusal::AboveBound(submission.answer.clone()))
+            }
+            Verdict::TooLow if value <= bound => {
+                return Err(Refusal::BelowBound(submission.answer.clone()))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns how long to wait until the server accepts the next answer, based on the latest submission.
+#[must_use]
+pub fn remaining_cooldown(log: &[Submission], now: u64) -> Option<Duration> {
+    let latest = log.iter().max_by_key(|s| s.timestamp)?;
+    let ready_at = latest.timestamp + latest.wait_secs?;
+    (ready_at > now).then(|| Duration::from_secs(ready_at - now))
+}
+
+/// Like [`remaining_cooldown`], at the current time.
+#[must_use]
+pub fn current_cooldown(log: &[Submission]) -> Option<Duration> {
+    remaining_cooldown(log, now())
+}
+
+/// Parses the time to wait from a message of the server, e.g. "You have 1m 5s left to wait" or "please wait 5 minutes".
+fn parse_wait(message: &str) -> Option<u64> {
+    let message = message.to_lowercase();
+
+    if let Some(end) = message.find(" left to wait") {
+        let start = message[..end].rfind("you have ")? + "you have ".len();
+
+        return message[start..end]
+            .split_whitespace()
+            .map(|x| {
+                let (value, unit) = x.split_at(x.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+
+    let start = message.find("please wait ")? + "please wait ".len();
+    let mut words = message[start..].split_whitespace();
+
+    let value = match words.next()? {
+        "one" | "a" => 1,
+        x => x.parse().ok()?,
+    };
+
+    match words.next()?.trim_end_matches(['.', ',']) {
+        "second" | "seconds" => Some(value),
+        "minute" | "minutes" => Some(value * 60),
+        "hour" | "hours" => Some(value * 3600),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{check, parse_log, parse_wait, remaining_cooldown, Refusal, Submission, Verdict};
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+            timestamp: 1_000,
+            wait_secs: None,
+        }
+    }
+
+    #[test]
+    fn reads_verdict_and_wait() {
+        let message = "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the about page, or you can ask for hints on the subreddit.  Please wait one minute before trying again. [Return to Day 7]";
+        let submission = Submission::new(1, "300", message);
+        assert_eq!(submission.verdict, Verdict::TooHigh);
+        assert_eq!(submission.wait_secs, Some(60));
+
+        let message = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. [Return to Day 7]";
+        assert_eq!(Verdict::from_message(message), Verdict::Cooldown);
+        assert_eq!(parse_wait(message), Some(65));
+
+        assert_eq!(
+            parse_wait("please wait 5 minutes before trying again."),
+            Some(300)
+        );
+        assert_eq!(
+            parse_wait("That's the right answer! You are one gold star closer."),
+            None
+        );
+
+        assert_eq!(
+            Verdict::from_message(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::from_message("your answer is too low"),
+            Verdict::TooLow
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = [
+            submission(1, "300", Verdict::TooHigh),
+            submission(1, "100", Verdict::TooLow),
+            submission(1, "abc", Verdict::Wrong),
+            submission(2, "7", Verdict::Correct),
+        ];
+
+        assert_eq!(check(&log, 1, "200"), Ok(()));
+        assert_eq!(
+            check(&log, 1, "abc"),
+            Err(Refusal::KnownWrong(Verdict::Wrong))
+        );
+        assert_eq!(
+            check(&log, 1, "300"),
+            Err(Refusal::KnownWrong(Verdict::TooHigh))
+        );
+        assert_eq!(
+            check(&log, 1, "301"),
+            Err(Refusal::AboveBound("300".into()))
+        );
+        assert_eq!(check(&log, 1, "42"), Err(Refusal::BelowBound("100".into())));
+        assert_eq!(check(&log, 2, "8"), Err(Refusal::AlreadySolved("7".into())));
+        assert_eq!(check(&[], 1, "300"), Ok(()));
+    }
+
+    #[test]
+    fn ignores_answers_not_checked() {
+        let log = [
+            submission(1, "300", Verdict::Cooldown),
+            submission(1, "200", Verdict::Unknown),
+        ];
+        assert_eq!(check(&log, 1, "300"), Ok(()));
+        assert_eq!(check(&log, 1, "250"), Ok(()));
+    }
+
+    #[test]
+    fn computes_cooldown_of_latest_submission() {
+        let mut first = submission(1, "1", Verdict::Wrong);
+        first.wait_secs = Some(300);
+        let mut latest = submission(1, "2", Verdict::Wrong);
+        latest.timestamp = 1_100;
+        latest.wait_secs = Some(60);
+
+        let log = [first, latest];
+        assert_eq!(
+            remaining_cooldown(&log, 1_130),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(remaining_cooldown(&log, 1_160), None);
+        assert_eq!(remaining_cooldown(&[], 1_160), None);
+    }
+
+    #[test]
+    fn round_trips_log() {
+        let mut submission = submission(2, "5905", Verdict::TooLow);
+        submission.wait_secs = Some(60);
+        let line = serde_json::to_string(&submission).unwrap();
+        assert!(line.contains(r#""verdict":"too_low""#));
+        assert_eq!(parse_log(&format!("{line}\n\n")).unwrap(), vec![submission]);
+        assert!(parse_log("{}").is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Day;
+
+/// A year of advent, i.e. 2015 or later.
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(2015..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// The default year of the workspace, configured by `AOC_YEAR` in `.cargo/config.toml`.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent, i.e. 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a puzzle by its year and day.
+///
+/// # Display
+/// This value displays as `YYYY/DD`, the name of the puzzle's binary is `YYYY_DD`.
+///
+/// ```
+/// # use advent_of_code::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2023).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2023/08");
+/// assert_eq!(puzzle.bin_name(), "2023_08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// The name of the binary that solves the puzzle, its source is `src/bin/{bin_name}.rs`.
+    pub fn bin_name(&self) -> String {
+        format!("{}_{}", self.year, self.day)
+    }
+
+    /// The path of a data file of the puzzle, e.g. `data/2023/inputs/08.txt`.
+    pub fn data_path(&self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year of advent, i.e. 2015 or later"
+            ),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year};
+    use crate::day;
+
+    #[test]
+    fn year_from_str() {
+        assert_eq!("2023".parse::<Year>().unwrap(), Year(2023));
+        assert_eq!("2015".parse::<Year>().unwrap().into_inner(), 2015);
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn puzzle_paths() {
+        let puzzle = PuzzleId::new(year!(2022), day!(5));
+        assert_eq!(puzzle.to_string(), "2022/05");
+        assert_eq!(puzzle.bin_name(), "2022_05");
+        assert_eq!(puzzle.data_path("inputs", "txt"), "data/2022/inputs/05.txt");
+        assert!(puzzle < PuzzleId::new(year!(2023), day!(1)));
+    }
+}
+
+/* -------------------------------------------------------------------------- */